@@ -1,16 +1,17 @@
+#[allow(unused_imports)]
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+#[allow(unused_imports)]
+use crate::{Answer, Problem};
 
 #[derive(Default)]
 pub struct Solution {
 }
 
 impl Problem for Solution {
-    //fn parse(&mut self, i: &[u8]) -> Result<()> {}
+    //fn parse(&mut self, i: &str) -> Result<()> {}
 
-    //fn part1(&self) -> Result<String> {}
+    //fn part1(&self) -> Result<Answer> {}
 
-    //fn part2(&self) -> Result<String> {}
+    //fn part2(&self) -> Result<Answer> {}
 }
-
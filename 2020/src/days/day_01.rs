@@ -3,7 +3,7 @@ use std::str;
 use anyhow::{anyhow, Result};
 use nom::combinator::all_consuming;
 
-use crate::Problem;
+use crate::{Answer, Problem};
 use crate::parsers;
 
 #[derive(Default)]
@@ -14,8 +14,8 @@ pub struct Solution {
 struct Data(Vec<i32>);
 
 impl Data {
-    fn new(i: &[u8]) -> Result<Data>{
-        let result = all_consuming(parsers::numbers_on_lines)(str::from_utf8(i)?)
+    fn new(i: &str) -> Result<Data>{
+        let result = all_consuming(parsers::numbers_on_lines)(i)
             .map_err(|e| anyhow!(format!("Parse error: {}:", e)))?;
         let mut numbers = result.1;
         numbers.sort();
@@ -52,20 +52,20 @@ impl Data {
 
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
+    fn parse(&mut self, i: &str) -> Result<()> {
 
         self.input = Some(Data::new(i)?);
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let data = self.input.as_ref().unwrap();
-        Ok(format!("{}", data.prod_of_sum2(2020)?))
+        Ok(data.prod_of_sum2(2020)?.into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         let data = self.input.as_ref().unwrap();
-        Ok(format!("{}", data.prod_of_sum3(2020)?))
+        Ok(data.prod_of_sum3(2020)?.into())
     }
 }
 
@@ -83,10 +83,10 @@ mod tests {
     #[test]
     fn test() {
         let mut sol = Solution::default();
-        assert!(sol.parse(TST.as_bytes()).is_ok());
+        assert!(sol.parse(TST).is_ok());
         assert_eq!(sol.input.as_ref().unwrap().0, vec![299, 366, 675, 979, 1456, 1721]);
 
-        assert_eq!(sol.part1().unwrap(), "514579");
-        assert_eq!(sol.part2().unwrap(), "241861950");
+        assert_eq!(sol.part1().unwrap().to_string(), "514579");
+        assert_eq!(sol.part2().unwrap().to_string(), "241861950");
     }
 }
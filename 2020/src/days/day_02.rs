@@ -3,7 +3,7 @@ use std::str;
 
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+use crate::{Answer, Problem};
 
 #[derive(Default)]
 pub struct Solution {
@@ -80,21 +80,21 @@ impl PwEntry {
 }
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse::pw_data(str::from_utf8(i)?)
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let result = parse::pw_data(i)
             .map_err(|e| anyhow!(e.to_string()))?;
 
         self.input = Some(Data(result.1));
         Ok(())
     }
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let data = self.input.as_ref().ok_or(anyhow!("not parsed"))?;
-        Ok(format!("{}", data.count_valid()))
+        Ok(data.count_valid().into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         let data = self.input.as_ref().ok_or(anyhow!("not parsed"))?;
-        Ok(format!("{}", data.count_valid_otcas()))
+        Ok(data.count_valid_otcas().into())
     }
 }
 
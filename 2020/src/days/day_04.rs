@@ -1,9 +1,11 @@
 use std::collections::HashMap;
 use std::str;
 
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 
-use crate::Problem;
+use aoc_core::record;
+use crate::parsers::{parse_all, ParseError};
+use crate::{Answer, Problem};
 
 #[derive(Default)]
 pub struct Solution {
@@ -12,11 +14,11 @@ pub struct Solution {
 
 use nom::{
     branch::alt,
-    bytes::complete::{is_not, take, take_while_m_n, tag},
+    bytes::complete::{take_while_m_n, tag},
     character::complete::{char, digit1, line_ending, space1},
     multi::{fold_many1, many1},
     combinator::{all_consuming, eof, verify},
-    sequence::{preceded, separated_pair, terminated, tuple},
+    sequence::{preceded, terminated, tuple},
     IResult,
 };
 
@@ -53,13 +55,13 @@ impl PassPort {
     }
 }
 
-fn parse(i: &str) -> IResult<&str, Vec<PassPort>> {
-    let item = separated_pair(take(3usize), char(':'), terminated(is_not("\r\n "), alt((space1, line_ending))));
-    let passport = fold_many1(item, PassPort(HashMap::new()), |mut pp: PassPort, (key, value): (&str, &str)| {
-        pp.0.insert(key.to_owned(), value.to_owned());
+fn parse(i: &str) -> Result<Vec<PassPort>, ParseError> {
+    let item = record!("{key:word}:{value:token}", |key: String, value: String| (key, value));
+    let passport = fold_many1(terminated(item, alt((space1, line_ending))), || PassPort(HashMap::new()), |mut pp: PassPort, (key, value)| {
+        pp.0.insert(key, value);
         pp
     });
-    all_consuming(many1(terminated(passport, alt((line_ending, eof)))))(i)
+    parse_all(i, many1(terminated(passport, alt((line_ending, eof)))))
 }
 
 //only used in verification:
@@ -91,20 +93,19 @@ fn hair(i: &str) -> IResult<&str, &str> {
 }
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse(str::from_utf8(i)?).map_err(|e| anyhow!(e.to_string()))?;
-        self.passports = result.1;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        self.passports = parse(i)?;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let valid = self.passports.iter().filter(|pp| pp.is_valid()).count();
-        Ok(format!("{}/{}", valid, self.passports.len()))
+        Ok(format!("{}/{}", valid, self.passports.len()).into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         let valid = self.passports.iter().filter(|pp| pp.is_valid_strict()).count();
-        Ok(format!("{}/{}", valid, self.passports.len()))
+        Ok(format!("{}/{}", valid, self.passports.len()).into())
     }
 }
 
@@ -128,9 +129,8 @@ hgt:179cm
 hcl:#cfa07d eyr:2025 pid:166559648
 iyr:2011 ecl:brn hgt:59in
 ";
-       let pp = parse(LIST).unwrap();
-        println!("{:?}", pp);
-        let l = pp.1;
+       let l = parse(LIST).unwrap();
+        println!("{:?}", l);
         assert_eq!(l.len(), 4);
         assert_eq!(l[2].0["hgt"], "179cm");
         assert_eq!(l.iter().filter(|pp| pp.is_valid()).count(), 2);
@@ -1,6 +1,6 @@
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+use crate::{Answer, Problem};
 
 #[derive(Default)]
 pub struct Solution {
@@ -31,18 +31,18 @@ fn parse(i: &[u8]) -> IResult<&[u8], Vec<u16>> {
 
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse(i).map_err(|e| anyhow!(e.to_string()))?;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let result = parse(i.as_bytes()).map_err(|e| anyhow!(e.to_string()))?;
         self.seats = result.1;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let highest = self.seats.iter().max().ok_or_else(|| anyhow!("No solution"))?;
-        Ok(format!("{}", highest))
+        Ok((*highest).into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         let mut ids: Vec<_> = self.seats.clone();
         ids.sort();
         let seat = ids.windows(2)
@@ -50,7 +50,7 @@ impl Problem for Solution {
             .map(|w| w[0]+1)
             .ok_or_else(|| anyhow!("No solution"))?;
 
-        Ok(format!("{:?}", seat))
+        Ok(seat.into())
     }
 }
 
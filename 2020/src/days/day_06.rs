@@ -1,6 +1,6 @@
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+use crate::{Answer, Problem};
 
 #[derive(Default)]
 pub struct Solution {
@@ -8,32 +8,29 @@ pub struct Solution {
 }
 
 use nom::{
-    branch::alt,
     bytes::complete::take_while1,
     character::is_alphabetic,
-    character::complete::line_ending,
-    multi::{many1, fold_many1},
-    combinator::{all_consuming, eof},
+    combinator::{all_consuming, map},
+    multi::many0,
     sequence::terminated,
     IResult
 };
+
+use crate::parsers::{lines, newline, sections};
+
 fn parse(i: &[u8]) -> IResult<&[u8], Vec<(Vec<u8>, usize)>> {
-    let person =  terminated(take_while1(is_alphabetic), line_ending);
-    let group = fold_many1(person, (Vec::new(), 0), |(mut v, n), p| {
-        v.extend(p);
-        (v, n+1)
-    });
-    all_consuming(many1(terminated(group, alt((line_ending, eof)))))(i)
+    let group = map(lines(take_while1(is_alphabetic)), |persons| (persons.concat(), persons.len()));
+    all_consuming(terminated(sections(group), many0(newline)))(i)
 }
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse(i).map_err(|e| anyhow!(e.to_string()))?;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let result = parse(i.as_bytes()).map_err(|e| anyhow!(e.to_string()))?;
         self.groups = result.1;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         //optimized version assumes no duplicate answers by persons
         let mut n_yes = 0;
         for g in &self.groups {
@@ -43,10 +40,10 @@ impl Problem for Solution {
             }
             n_yes += letters.count_ones();
         }
-        Ok(format!("{}", n_yes))
+        Ok(n_yes.into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         //optimized version assumes no duplicate answers by persons
         let mut n_yes = 0;
         for g in &self.groups {
@@ -56,7 +53,7 @@ impl Problem for Solution {
             }
             n_yes += letters.iter().filter(|&&l| l == g.1).count();
         }
-        Ok(format!("{}", n_yes))
+        Ok(n_yes.into())
     }
 }
 
@@ -3,7 +3,7 @@ use std::str;
 
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+use crate::{Answer, Problem};
 
 type Rules = HashMap<Color, Vec<(usize, Color)>>;
 
@@ -94,20 +94,20 @@ fn contains(bag: &Color, l: &Rules) -> usize {
 }
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse(str::from_utf8(i)?).map_err(|e| anyhow!(e.to_string()))?;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let result = parse(i).map_err(|e| anyhow!(e.to_string()))?;
         self.rules = result.1.into_iter().collect();
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let wanted = "shiny gold".to_owned();
-        Ok(format!("{}", can_contain(&wanted, &self.rules)))
+        Ok(can_contain(&wanted, &self.rules).into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         let wanted = "shiny gold".to_owned();
-        Ok(format!("{}", contains(&wanted, &self.rules)))
+        Ok(contains(&wanted, &self.rules).into())
     }
 }
 
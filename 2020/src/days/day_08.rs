@@ -2,7 +2,7 @@ use std::str;
 
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+use crate::{Answer, Problem};
 use crate::vm::*;
 use crate::parsers::signed_integer;
 
@@ -49,22 +49,22 @@ fn mutate(p: &[Instr], pos: usize) -> impl Iterator<Item=Instr> + '_ {
 }
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse(str::from_utf8(i)?).map_err(|e| anyhow!(e.to_string()))?;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let result = parse(i).map_err(|e| anyhow!(e.to_string()))?;
         self.program = result.1;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let mut vm = Vm::from_program(self.program.iter().copied());
         match vm.run() {
             Err(e) if e == VmError::InfiniteLoop => Ok(()),
             x => x
         }?;
-        Ok(format!("{}", vm.acc()))
+        Ok(vm.acc().into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         let acc_ok = (0..self.program.len())
             .filter(|&i| !matches!(self.program[i], Instr::Acc(_)))
             .find_map(|mut_ins| {
@@ -77,7 +77,7 @@ impl Problem for Solution {
             }).ok_or_else(|| anyhow!("No solution"))?;
 
 
-        Ok(format!("{:?}", acc_ok))
+        Ok(acc_ok.into())
     }
 }
 
@@ -8,7 +8,7 @@ use nom::{
     sequence::terminated,
 };
 
-use crate::Problem;
+use crate::{Answer, Problem};
 use crate::parsers;
 
 #[derive(Default)]
@@ -59,21 +59,21 @@ fn find_sum(v: &[u64], sum: u64) -> Result<u64> {
 }
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
+    fn parse(&mut self, i: &str) -> Result<()> {
         let result = all_consuming(many1(terminated(parsers::positive_integer, line_ending)))
-            (str::from_utf8(i)?).map_err(|e| anyhow!(e.to_string()))?;
+            (i).map_err(|e| anyhow!(e.to_string()))?;
 
         self.input = result.1;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
-        Ok(format!("{}", find_invalid(&self.input, 25)?))
+    fn part1(&self) -> Result<Answer> {
+        Ok(find_invalid(&self.input, 25)?.into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         //FIXME not rerunning part 1 makes this specific for my input....
-        Ok(format!("{}", find_sum(&self.input, 1930745883)?))
+        Ok(find_sum(&self.input, 1930745883)?.into())
     }
 }
 
@@ -8,7 +8,7 @@ use nom::{
     sequence::terminated,
 };
 
-use crate::Problem;
+use crate::{Answer, Problem};
 use crate::parsers;
 
 #[derive(Default)]
@@ -55,21 +55,21 @@ fn count_valid(v: &[u8]) -> usize {
 }
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
+    fn parse(&mut self, i: &str) -> Result<()> {
         let result = all_consuming(many1(terminated(parsers::positive_integer, line_ending)))
-            (str::from_utf8(i)?).map_err(|e| anyhow!(e.to_string()))?;
+            (i).map_err(|e| anyhow!(e.to_string()))?;
 
         self.input = result.1;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let (d1, d3) = diff_1_3(&self.input);
-        Ok(format!("{}", d1 * d3))
+        Ok((d1 * d3).into())
     }
 
-    fn part2(&self) -> Result<String> {
-        Ok(format!("{}", count_valid(&self.input)))
+    fn part2(&self) -> Result<Answer> {
+        Ok(count_valid(&self.input).into())
     }
 }
 
@@ -2,7 +2,7 @@ use std::str;
 
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+use crate::{Answer, Problem};
 use crate::parsers::positive_integer;
 
 #[derive(Default)]
@@ -124,25 +124,25 @@ fn follow_da_waypoint(boat: &mut Position, waypoint: &mut Position, program: &[M
 }
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse(str::from_utf8(i)?).map_err(|e| anyhow!(e.to_string()))?;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let result = parse(i).map_err(|e| anyhow!(e.to_string()))?;
         self.program = result.1;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let mut boat = Position { x: 0, y: 0, direction: 90 };
         for &m in &self.program {
             boat.do_move(m);
         }
-        Ok(format!("{}", boat.dist_to_origin()))
+        Ok(boat.dist_to_origin().into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         let mut boat = Position { x: 0, y: 0, direction: 90 };
         let mut waypoint = Position { x: 10, y: 1, direction: 00 };
         follow_da_waypoint(&mut boat, &mut waypoint, &self.program);
-        Ok(format!("{}", boat.dist_to_origin()))
+        Ok(boat.dist_to_origin().into())
     }
 }
 
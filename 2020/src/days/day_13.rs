@@ -2,7 +2,7 @@ use std::str;
 
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+use crate::{Answer, Problem};
 use crate::parsers::positive_integer;
 
 use nom::{
@@ -50,14 +50,14 @@ fn solve_rem(v: &[(i64, i64)]) -> i64 {
 }
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse(str::from_utf8(i)?).map_err(|e| anyhow!(e.to_string()))?;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let result = parse(i).map_err(|e| anyhow!(e.to_string()))?;
         self.start = result.1.0;
         self.list = result.1.1;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let (id, wait) = self.list.iter()
             .filter_map(|&v| v)
             .map(|v| {
@@ -67,10 +67,10 @@ impl Problem for Solution {
                 }
             }).min_by_key(|t| t.1).unwrap();
         
-        Ok(format!("{}", id * wait))
+        Ok((id * wait).into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
 
         let idtime: Vec<_> =   self.list.iter()
             .enumerate()
@@ -79,7 +79,7 @@ impl Problem for Solution {
             .collect();
         //all line ids are prime. So it seems I finally have to look into the chinese
         //remainder theorem. Forgive me my implementation, total noob here.
-        Ok(format!("{}", solve_rem(&idtime)))
+        Ok(solve_rem(&idtime).into())
     }
 }
 
@@ -3,7 +3,7 @@ use std::str;
 
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+use crate::{Answer, Problem};
 use crate::parsers::positive_integer;
 
 use nom::{
@@ -31,7 +31,7 @@ enum Operation {
 }
 
 fn mask_line(i: &str) -> IResult<&str,Operation> {
-    let mask = map(fold_many_m_n(36,36, one_of("X01"), (0xFFFFFFF000000000, 0, 35), |(mut mnot, mut mor, bitnr), c| {
+    let mask = map(fold_many_m_n(36,36, one_of("X01"), || (0xFFFFFFF000000000, 0, 35), |(mut mnot, mut mor, bitnr), c| {
         match c {
             '0' => mnot |= 1 << bitnr,
             '1' => mor |= 1 << bitnr,
@@ -120,18 +120,18 @@ fn run_program_alt(p: &[Operation]) -> u64{
 
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse(str::from_utf8(i)?).map_err(|e| anyhow!(e.to_string()))?;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let result = parse(i).map_err(|e| anyhow!(e.to_string()))?;
         self.program = result.1;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
-        Ok(format!("{}", run_program(&self.program)))
+    fn part1(&self) -> Result<Answer> {
+        Ok(run_program(&self.program).into())
     }
 
-    fn part2(&self) -> Result<String> {
-        Ok(format!("{}", run_program_alt(&self.program)))
+    fn part2(&self) -> Result<Answer> {
+        Ok(run_program_alt(&self.program).into())
     }
 }
 
@@ -2,7 +2,7 @@ use std::str;
 
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+use crate::{Answer, Problem};
 use crate::parsers::positive_integer;
 
 use nom::{
@@ -59,18 +59,18 @@ fn parse(i: &str) -> IResult<&str,Vec<usize>> {
 }
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse(str::from_utf8(i)?).map_err(|e| anyhow!(e.to_string()))?;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let result = parse(i).map_err(|e| anyhow!(e.to_string()))?;
         self.input = result.1;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
-        Ok(format!("{}",NumbersIterator::new(&self.input).nth(2019-self.input.len()).unwrap()))
+    fn part1(&self) -> Result<Answer> {
+        Ok((NumbersIterator::new(&self.input).nth(2019-self.input.len()).unwrap()).into())
     }
 
-    fn part2(&self) -> Result<String> {
-        Ok(format!("{}", NumbersIterator::new(&self.input).nth(29999999-self.input.len()).unwrap()))
+    fn part2(&self) -> Result<Answer> {
+        Ok((NumbersIterator::new(&self.input).nth(29999999-self.input.len()).unwrap()).into())
     }
 }
 
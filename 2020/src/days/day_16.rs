@@ -4,7 +4,7 @@ use std::ops::RangeInclusive;
 
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+use crate::{Answer, Problem};
 use crate::parsers::{commasep_positive_integer, range_positive_integer};
 
 use nom::{
@@ -128,20 +128,20 @@ fn parse(i: &str) -> IResult<&str, Data> {
 }
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse(str::from_utf8(i)?).map_err(|e| anyhow!(e.to_string()))?;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let result = parse(i).map_err(|e| anyhow!(e.to_string()))?;
         self.input = Some(result.1);
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let data = self.input.as_ref().ok_or_else(|| anyhow!("Not parsed"))?;
-        Ok(format!("{}", data.completely_invalid()))
+        Ok(data.completely_invalid().into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         let data = self.input.as_ref().ok_or_else(|| anyhow!("Not parsed"))?;
-        Ok(format!("{}", data.decode()))
+        Ok(data.decode().into())
     }
 }
 
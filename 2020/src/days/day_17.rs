@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+use crate::{Answer, Problem};
 
 use nom::{
     bytes::complete::is_a,
@@ -162,7 +162,7 @@ fn neigh(i: i32) -> std::ops::Range<i32> {
 
 fn parse(i: &[u8]) -> IResult<&[u8], Grid> {
     let gridline = terminated(is_a("#."), line_ending);
-    let grid = fold_many1(gridline, (Grid::new(), 0i32), |(mut grid, y), line: &[u8]| {
+    let grid = fold_many1(gridline, || (Grid::new(), 0i32), |(mut grid, y), line: &[u8]| {
         //dimnension checks?
         for (x, &c) in line.iter().enumerate() {
             grid.set((x as i32, y, 0), c);
@@ -175,26 +175,26 @@ fn parse(i: &[u8]) -> IResult<&[u8], Grid> {
 
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse(i).map_err(|e| anyhow!(e.to_string()))?;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let result = parse(i.as_bytes()).map_err(|e| anyhow!(e.to_string()))?;
         self.input = result.1;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let mut grid = self.input.clone();
         for _i in 0..6 {
             grid.step_life();
         }
-        Ok(format!("{}", grid.count_active()))
+        Ok(grid.count_active().into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         let mut hypergrid = HyperGrid::from_grid(&self.input);
         for _i in 0..6 {
             hypergrid.step_life();
         }
-        Ok(format!("{}", hypergrid.count_active()))
+        Ok(hypergrid.count_active().into())
     }
 }
 
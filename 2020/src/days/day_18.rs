@@ -2,7 +2,7 @@ use std::str;
 
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+use crate::{Answer, Problem};
 
 use nom::{
     branch::alt,
@@ -57,7 +57,7 @@ fn value(i: &str) -> IResult<&str, u64> {
 
 fn no_precedence(i: &str) -> IResult<&str, u64> {
     let (i, v) = value(i)?;
-    fold_many1(tuple((space_padded(operator), value)), v, |mut acc, (op, val)| {
+    fold_many1(tuple((space_padded(operator), value)), move || v, |mut acc, (op, val)| {
         match op {
             Op::Add => acc += val,
             Op::Mul => acc *= val,
@@ -85,7 +85,7 @@ fn value2(i: &str) -> IResult<&str, u64> {
 
 fn plus_precedence(i: &str) -> IResult<&str, u64> {
     let (i, v) = value2(i)?;
-    fold_many0(product, v, |mut acc, val| {
+    fold_many0(product, move || v, |mut acc, val| {
         acc *= val;
         acc
     })(i)
@@ -95,25 +95,25 @@ fn plus_precedence(i: &str) -> IResult<&str, u64> {
 fn parse<'a, F>(parser: F) -> impl FnMut(&'a str) -> IResult<&'a str, u64, nom::error::Error<&'a str>>
 where F: Parser<&'a str, u64, nom::error::Error<&'a str>>,
 {
-    all_consuming(fold_many1(terminated(parser, line_ending), 0, |acc, val| {
+    all_consuming(fold_many1(terminated(parser, line_ending), || 0, |acc, val| {
         acc + val
     }))
 }
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        self.input = String::from_utf8(i.to_vec())?;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        self.input = i.to_string();
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let result = parse(no_precedence)(self.input.as_str()).map_err(|e| anyhow!(e.to_string()))?;
-        Ok(format!("{}", result.1))
+        Ok(result.1.into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         let result = parse(plus_precedence)(self.input.as_str()).map_err(|e| anyhow!(e.to_string()))?;
-        Ok(format!("{}", result.1))
+        Ok(result.1.into())
     }
 }
 
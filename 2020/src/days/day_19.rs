@@ -3,7 +3,7 @@ use std::str;
 
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+use crate::{Answer, Problem};
 #[derive(Default)]
 pub struct Solution {
     input: Data
@@ -102,21 +102,21 @@ fn parse(i: &str) -> IResult<&str, Data> {
 
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse(str::from_utf8(i)?).map_err(|e| anyhow!(e.to_string()))?;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let result = parse(i).map_err(|e| anyhow!(e.to_string()))?;
         self.input = result.1;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let valid = &self.input.messages
             .iter()
             .filter(|m| rule_substrs(m, 0, &self.input.rules).iter().any(|remain| remain.len() == 0))
             .count();
-        Ok(format!("{}", valid))
+        Ok((*valid).into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         // rule 0 : 8 11
         // rule 8 : 42
         // rule 11: 42 31
@@ -168,7 +168,7 @@ impl Problem for Solution {
                 }
             }
         }
-        Ok(format!("{}", valid))
+        Ok(valid.into())
     }
 }
 
@@ -5,7 +5,7 @@ use std::str;
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
 
-use crate::Problem;
+use crate::{Answer, Problem};
 use nom::{
     bytes::complete::tag,
     character::complete::{alpha1, char, line_ending, space0, space1},
@@ -86,20 +86,20 @@ fn parse(i: &str) -> IResult<&str, Ingredients> {
 }
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse(str::from_utf8(i)?).map_err(|e| anyhow!(e.to_string()))?;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let result = parse(i).map_err(|e| anyhow!(e.to_string()))?;
         self.input = Some(result.1);
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
-        Ok(format!("See part2"))
+    fn part1(&self) -> Result<Answer> {
+        Ok("See part2".into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         let data = self.input.as_ref().ok_or_else(|| anyhow!("Not parsed"))?;
         let (p1, p2) = decode(data);
-        Ok(format!("{}\t{}", p1, p2))
+        Ok(format!("{}\t{}", p1, p2).into())
     }
 }
 
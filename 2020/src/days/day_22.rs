@@ -4,7 +4,7 @@ use std::str;
 
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+use crate::{Answer, Problem};
 use crate::parsers::positive_integer;
 
 use nom::{
@@ -129,19 +129,19 @@ fn rec_round(p1: &mut VecDeque<u8>, p2: &mut VecDeque<u8>) -> usize {
 }
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse(str::from_utf8(i)?).map_err(|e| anyhow!(e.to_string()))?;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let result = parse(i).map_err(|e| anyhow!(e.to_string()))?;
         self.deck_1 = result.1.0;
         self.deck_2 = result.1.1;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
-        Ok(format!("{}", play(&self.deck_1, &self.deck_2)))
+    fn part1(&self) -> Result<Answer> {
+        Ok(play(&self.deck_1, &self.deck_2).into())
     }
 
-    fn part2(&self) -> Result<String> {
-        Ok(format!("{}", play_rec(&self.deck_1, &self.deck_2)))
+    fn part2(&self) -> Result<Answer> {
+        Ok(play_rec(&self.deck_1, &self.deck_2).into())
     }
 }
 
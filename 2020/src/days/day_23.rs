@@ -2,7 +2,7 @@ use std::str;
 
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+use crate::{Answer, Problem};
 
 use nom::{
     branch::alt,
@@ -146,19 +146,19 @@ fn parse(i: &str) -> IResult<&str, Vec<u32>> {
 }
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse(str::from_utf8(i)?).map_err(|e| anyhow!(e.to_string()))?;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let result = parse(i).map_err(|e| anyhow!(e.to_string()))?;
         self.cups = result.1;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let res = play(&self.cups, 100);
-        Ok(string_from_one(&res))
+        Ok(string_from_one(&res).into())
     }
 
-    fn part2(&self) -> Result<String> {
-        Ok(format!("{}", play_big(&self.cups, 1_000_000, 10_000_000)))
+    fn part2(&self) -> Result<Answer> {
+        Ok(play_big(&self.cups, 1_000_000, 10_000_000).into())
     }
 }
 
@@ -1,9 +1,8 @@
-use std::collections::HashMap;
-
 use anyhow::{anyhow, Result};
 use smallvec::SmallVec;
 
-use crate::Problem;
+use crate::{Answer, Problem};
+use crate::grid::{Coord, SparseGrid};
 
 use nom::{
     branch::alt,
@@ -31,7 +30,8 @@ enum Direction {
     NE
 }
 
-type Floor = HashMap<(i32, i32), bool>;
+/// Tiles are white (`true`) until flipped, rows are offset so odd rows sit half a tile east
+type Floor = SparseGrid<bool>;
 
 fn parse(i: &[u8]) -> IResult<&[u8], Vec<Walk>> {
     let e = map(tag("e"), |_| Direction::E);
@@ -44,28 +44,28 @@ fn parse(i: &[u8]) -> IResult<&[u8], Vec<Walk>> {
     all_consuming(many1(walk))(i)
 }
 
-fn walk(w: &Walk) -> (i32, i32) {
-    let mut coord = (0,0);
+fn walk(w: &Walk) -> Coord {
+    let mut coord = Coord::default();
     for d in w {
-        let even = coord.1 % 2 == 0;
+        let even = coord.y % 2 == 0;
         match d {
-            Direction::E => coord.0 += 1,
-            Direction::SE if even => coord.1 += 1,
-            Direction::SE => {coord.0 += 1; coord.1 += 1},
-            Direction::SW if even => { coord.0 -= 1; coord.1 += 1 },
-            Direction::SW => coord.1 += 1,
-            Direction::W => coord.0 -= 1,
-            Direction::NW if even => { coord.0 -= 1; coord.1 -= 1 },
-            Direction::NW => coord.1 -= 1,
-            Direction::NE if even => coord.1 -= 1,
-            Direction::NE => {coord.0 += 1; coord.1 -= 1},
+            Direction::E => coord.x += 1,
+            Direction::SE if even => coord.y += 1,
+            Direction::SE => {coord.x += 1; coord.y += 1},
+            Direction::SW if even => { coord.x -= 1; coord.y += 1 },
+            Direction::SW => coord.y += 1,
+            Direction::W => coord.x -= 1,
+            Direction::NW if even => { coord.x -= 1; coord.y -= 1 },
+            Direction::NW => coord.y -= 1,
+            Direction::NE if even => coord.y -= 1,
+            Direction::NE => {coord.x += 1; coord.y -= 1},
         }
     }
     coord
 }
 
 fn do_flips(instr: &[Walk]) -> Floor {
-    let mut floor = HashMap::new();
+    let mut floor = SparseGrid::new(true);
     for w in instr {
         let coord = walk(w);
         flip_tile(coord, &mut floor);
@@ -74,17 +74,17 @@ fn do_flips(instr: &[Walk]) -> Floor {
 }
 
 fn count_flipped<F: std::borrow::Borrow<Floor>>(floor: F) -> usize {
-    floor.borrow().values().filter(|&&t| !t).count()
+    floor.borrow().iter().filter(|&(_, &t)| !t).count()
 }
 
 fn do_life(instr: &[Walk], ndays: usize) -> usize {
     let floor = (0..ndays).fold(do_flips(instr), |mut floor, _| {
         let changes: Vec<_> = floor.iter().filter_map(|(coord, &t)| {
-            let c = count_black_neigh(*coord, &floor);
+            let c = count_black_neigh(coord, &floor);
             if !t && (c == 0 || c > 2) {
-                Some(*coord)
+                Some(coord)
             } else if t && c == 2 {
-                Some(*coord)
+                Some(coord)
             } else {
                 None
             }
@@ -95,42 +95,37 @@ fn do_life(instr: &[Walk], ndays: usize) -> usize {
     count_flipped(floor)  
 }
 
-fn neighbors(c: (i32, i32)) -> SmallVec<[(i32, i32); 6]> {
+fn neighbors(c: Coord) -> SmallVec<[Coord; 6]> {
     //common
     let mut res = SmallVec::new();
-    res.push((c.0-1, c.1));
-    res.push((c.0, c.1-1));
-    res.push((c.0, c.1+1));
-    res.push((c.0+1, c.1));
-
-    if c.1 % 2 == 0 {
-        res.push((c.0-1, c.1-1));
-        res.push((c.0-1, c.1+1));
+    res.push((c.x-1, c.y).into());
+    res.push((c.x, c.y-1).into());
+    res.push((c.x, c.y+1).into());
+    res.push((c.x+1, c.y).into());
+
+    if c.y % 2 == 0 {
+        res.push((c.x-1, c.y-1).into());
+        res.push((c.x-1, c.y+1).into());
     } else {
-        res.push((c.0+1, c.1-1));
-        res.push((c.0+1, c.1+1));
+        res.push((c.x+1, c.y-1).into());
+        res.push((c.x+1, c.y+1).into());
     }
 
     res
 }
 
-fn count_black_neigh(c: (i32, i32), floor: &Floor) -> usize {
-    let mut count = 0;
-    for c in neighbors(c) {
-        if let Some(false) = floor.get(&c) { count += 1; }
-    }
-
-    count
+fn count_black_neigh(c: Coord, floor: &Floor) -> usize {
+    neighbors(c).into_iter().filter(|&n| !floor[n]).count()
 }
 
-fn flip_tile(c: (i32, i32), floor: &mut Floor) {
-    let e = floor.entry(c).or_insert(true);
+fn flip_tile(c: Coord, floor: &mut Floor) {
+    let e = floor.entry(c);
     match &e {
         true => {
             *e = false;
             //add  neighbor whites
             for c in neighbors(c) {
-                floor.entry(c).or_insert(true);
+                floor.entry(c);
             }
         },
         false => *e = true,
@@ -138,19 +133,19 @@ fn flip_tile(c: (i32, i32), floor: &mut Floor) {
 }
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse(i).map_err(|e| anyhow!(e.to_string()))?;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let result = parse(i.as_bytes()).map_err(|e| anyhow!(e.to_string()))?;
         self.input = result.1;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
-        Ok(format!("{}", count_flipped(do_flips(&self.input))))
+    fn part1(&self) -> Result<Answer> {
+        Ok((count_flipped(do_flips(&self.input))).into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
 
-        Ok(format!("{}", do_life(&self.input, 100)))
+        Ok(do_life(&self.input, 100).into())
     }
 }
 
@@ -2,7 +2,7 @@ use std::str;
 
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+use crate::{Answer, Problem};
 use crate::parsers::positive_integer;
 
 use nom::{
@@ -35,20 +35,20 @@ fn crack_ls(sj: usize, key: usize) -> usize {
 }
 
 impl Problem for Solution {
-    fn parse(&mut self, i: &[u8]) -> Result<()> {
-        let result = parse(str::from_utf8(i)?).map_err(|e| anyhow!(e.to_string()))?;
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let result = parse(i).map_err(|e| anyhow!(e.to_string()))?;
         self.card_key = result.1.0;
         self.lock_key = result.1.1;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let ls_card = crack_ls(7, self.card_key);
-        Ok(format!("{}", transform(self.lock_key, ls_card)))
+        Ok(transform(self.lock_key, ls_card).into())
     }
 
-    fn part2(&self) -> Result<String> {
-        Ok(format!("Merry X-mas"))
+    fn part2(&self) -> Result<Answer> {
+        Ok("Merry X-mas".into())
     }
 }
 
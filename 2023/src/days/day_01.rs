@@ -12,7 +12,7 @@ sequence::terminated,
 };
 
 use crate::parsers::{single_dec_digit, single_alpha};
-use crate::Problem;
+use crate::{Answer, Problem};
 
 #[derive(Default)]
 pub struct Solution{
@@ -92,18 +92,18 @@ impl Problem for Solution {
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
        Ok(self.data.iter()
            .map(|e| e.calib_value_digits())
            .sum::<u32>()
-           .to_string())
+           .into())
     }
 
-   fn part2(&self) -> Result<String> {
+   fn part2(&self) -> Result<Answer> {
        Ok(self.data.iter()
            .map(|e| e.calib_value_both())
            .sum::<u32>()
-           .to_string())
+           .into())
    }
 }
 
@@ -1,24 +1,20 @@
-#[allow(unused_imports)]
-use anyhow::{anyhow, Result};
-use nom::{IResult,
-branch::alt,
-bytes::complete::{tag},
-character::complete::{digit1, line_ending, space1},
-combinator::{opt},
-multi::{many1, separated_list1},
-sequence::{delimited, terminated, separated_pair}
+use anyhow::Result;
+use nom::{
+    character::complete::line_ending,
+    multi::many1,
+    sequence::terminated,
 };
 
-use crate::parsers::{positive_integer};
-use crate::Problem;
+use aoc_core::record;
+use crate::parsers::parse_all;
+use crate::parsers::record::FromField;
+use crate::{Answer, Solver};
 
-#[derive(Default)]
-pub struct Solution {
-    data: Vec<Game>,
-    limit_red: u32,
-    limit_green: u32,
-    limit_blue: u32,
-}
+pub struct Solution;
+
+const LIMIT_RED: u32 = 12;
+const LIMIT_GREEN: u32 = 13;
+const LIMIT_BLUE: u32 = 14;
 
 #[derive(Default, Debug)]
 struct Draw {
@@ -28,15 +24,18 @@ struct Draw {
 }
 
 #[derive(Default, Debug)]
-struct Game(Vec<Draw>);
+pub struct Game {
+    id: u32,
+    draws: Vec<Draw>,
+}
 
 impl Game {
     fn is_possible(&self, limit_red: u32, limit_green: u32, limit_blue: u32) -> bool {
-        self.0.iter().all(|draw| draw.red <= limit_red && draw.green <= limit_green && draw.blue <= limit_blue)
+        self.draws.iter().all(|draw| draw.red <= limit_red && draw.green <= limit_green && draw.blue <= limit_blue)
     }
 
     fn power(&self) -> u32 {
-        let (r, g, b) = self.0.iter()
+        let (r, g, b) = self.draws.iter()
             .fold((0,0,0), |acc, draw| {
                 (acc.0.max(draw.red), acc.1.max(draw.green), acc.2.max(draw.blue))
         });
@@ -46,59 +45,70 @@ impl Game {
 }
 
 
-fn parse_draw(mut s: &str) -> IResult<&str, Draw> {
-    let mut color = separated_pair(positive_integer, space1, alt((tag("red"), tag("green"), tag("blue"))));
-
+/// A draw like `3 blue, 4 red`
+fn parse_draw(s: &str) -> Option<Draw> {
     let mut draw = Draw::default();
-    while let Ok((ss, (n, c))) = terminated(&mut color, opt(tag(", ")))(s) {
-        match c {
+    for cubes in s.split(',') {
+        let (n, color) = cubes.trim().split_once(' ')?;
+        let n = n.parse().ok()?;
+        match color {
             "red" => draw.red = n,
             "green" => draw.green = n,
             "blue" => draw.blue = n,
-            _ => unreachable!(),
+            _ => return None,
         }
-        s = ss;
     }
-
-    Ok((s, draw))
+    Some(draw)
 }
 
-fn parse_line(s: &str) -> IResult<&str, Game> {
-    // ignore num, assume ascending numbers. We can trust Eric? Right?
-    let (s, _num) = delimited(tag("Game "), digit1, tag(": "))(s)?;
-
-    let (s, draws) = separated_list1(tag("; "), parse_draw)(s)?;
+/// The draws of a game, separated by `;`
+struct Draws(Vec<Draw>);
 
-    Ok((s, Game(draws)))
+impl FromField for Draws {
+    fn from_field(s: &str) -> Option<Draws> {
+        s.split(';').map(parse_draw).collect::<Option<_>>().map(Draws)
+    }
 }
 
+impl Solver for Solution {
+    type Input = Vec<Game>;
 
-impl Problem for Solution {
-    fn parse(&mut self, s: &str) -> Result<()> {
-        let (_s, items) = many1(terminated(parse_line, line_ending))(s).unwrap();
-        self.data = items;
-
-        self.limit_red = 12;
-        self.limit_green = 13;
-        self.limit_blue = 14;
+    fn parse(s: &str) -> Result<Vec<Game>> {
+        let game = record!("Game {id:int}: {draws}", |id: u32, draws: Draws| Game { id, draws: draws.0 });
+        let games = parse_all(s, many1(terminated(game, line_ending)))?;
 
-        Ok(())
+        Ok(games)
     }
 
-    fn part1(&self) -> Result<String> {
-        Ok(self.data.iter()
-           .enumerate()
-           .filter(|(_, g)| g.is_possible(self.limit_red, self.limit_green, self.limit_blue))
-           .map(|(id, _)| id + 1)
-           .sum::<usize>()
-           .to_string())
+    fn part1(games: &Vec<Game>) -> Result<Answer> {
+        Ok(games.iter()
+           .filter(|g| g.is_possible(LIMIT_RED, LIMIT_GREEN, LIMIT_BLUE))
+           .map(|g| g.id)
+           .sum::<u32>()
+           .into())
     }
 
-   fn part2(&self) -> Result<String> {
-        Ok(self.data.iter()
+   fn part2(games: &Vec<Game>) -> Result<Answer> {
+        Ok(games.iter()
            .map(|game| game.power())
            .sum::<u32>()
-           .to_string())
+           .into())
    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parts() {
+        let games = Solution::parse(r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+"#).unwrap();
+        assert_eq!(Solution::part1(&games).unwrap(), Answer::UInt(8));
+        assert_eq!(Solution::part2(&games).unwrap(), Answer::UInt(2286));
+    }
+}
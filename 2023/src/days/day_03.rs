@@ -10,7 +10,7 @@ use nom::{
 };
 
 use crate::parsers::positive_integer;
-use crate::Problem;
+use crate::{Answer, Problem};
 
 type Numbers = HashMap<(u32, u32), (usize, u32)>;
 type Symbols = HashMap<(u32, u32), char>;
@@ -123,43 +123,16 @@ impl Problem for Solution {
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
-        Ok(self.numbers_adj_symbols().to_string())
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.numbers_adj_symbols().into())
     }
 
-    fn part2(&self) -> Result<String> {
-        Ok(self.gear_ratios().to_string())
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.gear_ratios().into())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse() {
-        let input = r#"467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598..
-"#;
-
-        let (rem, (numbers, symbols)) = parse(input).unwrap();
-        assert_eq!(rem, "");
-        assert_eq!(symbols.len(), 6);
-
-        assert_eq!(numbers.get(&(0, 4)), Some(&(4, 617)));
-        assert_eq!(symbols.get(&(3, 4)), Some(&'*'));
-
-        let sol = Solution { numbers, symbols };
-        assert_eq!(sol.numbers_adj_symbols(), 4361);
-        assert_eq!(sol.gear_ratios(), 467835);
-    }
+    aoc_core::example_test!(example, 3, "day_03_1.txt", part1 = 4361, part2 = 467835);
 }
-
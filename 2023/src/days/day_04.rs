@@ -1,25 +1,20 @@
 use std::collections::HashSet;
 use std::iter::FromIterator;
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 
 use nom::{
-    bytes::complete::{tag},
-    character::complete::{line_ending, space0, space1},
-    combinator::map,
-    multi::{fold_many1, many1, separated_list1},
-    sequence::{delimited, separated_pair, terminated},
-    IResult
+    character::complete::line_ending,
+    multi::many1,
+    sequence::terminated,
 };
 
-use crate::parsers::positive_integer;
-use crate::Problem;
+use aoc_core::record;
+use crate::parsers::parse_all;
+use crate::{Answer, Solver};
 
-#[derive(Default)]
-pub struct Solution {
-    data: Vec<Card>,
-}
+pub struct Solution;
 
-struct Card {
+pub struct Card {
     num: u32,
     list1: u128,
     list2: u128,
@@ -40,44 +35,30 @@ impl Card {
     }
 }
 
-fn list(s: &str) -> IResult<&str, u128> {
-    fold_many1(terminated(positive_integer,space0),
-        || 0u128,
-        |acc, v: u32| acc | (1 << v)
-    )(s)
+fn bits(numbers: &[u32]) -> u128 {
+    numbers.iter().fold(0, |acc, v| acc | (1 << v))
 }
 
-fn parse_card(s: &str) -> IResult<&str, Card> {
-    let (s, num) = delimited(
-        terminated(tag("Card"), space1),
-        positive_integer,
-        terminated(tag(":"), space1))(s)?;
-
-    let (s, (list1, list2)) = separated_pair(list, delimited(space0, tag("|"), space0), list)(s)?;
-
-    Ok((s, Card { num, list1, list2 }))
-}
-
-
-impl Problem for Solution {
-    fn parse(&mut self, s: &str) -> Result<()> {
-        let (_s, cards) = many1(terminated(parse_card, line_ending))(s)
-            .map_err(|e| anyhow!("Error parsing cards: {}", e))?;
-
-        self.data = cards;
+impl Solver for Solution {
+    type Input = Vec<Card>;
 
-        Ok(())
+    fn parse(s: &str) -> Result<Vec<Card>> {
+        let card = record!("Card {num:int}: {list1:list} | {list2:list}", |num: u32, list1: Vec<u32>, list2: Vec<u32>| {
+            Card { num, list1: bits(&list1), list2: bits(&list2) }
+        });
+        let cards = parse_all(s, many1(terminated(card, line_ending)))?;
 
+        Ok(cards)
     }
 
-    fn part1(&self) -> Result<String> {
-        Ok(self.data.iter().map(|c| c.score()).sum::<u32>().to_string())
+    fn part1(cards: &Vec<Card>) -> Result<Answer> {
+        Ok(cards.iter().map(|c| c.score()).sum::<u32>().into())
     }
 
-    fn part2(&self) -> Result<String> {
-        let scores: Vec<_> = self.data.iter().map(|c| c.matching() as usize).collect();
+    fn part2(cards: &Vec<Card>) -> Result<Answer> {
+        let scores: Vec<_> = cards.iter().map(|c| c.matching() as usize).collect();
 
-        let len = self.data.len();
+        let len = cards.len();
         let mut have_cards = vec![1u64; len];
 
         for pos in 0..len {
@@ -91,7 +72,7 @@ impl Problem for Solution {
         }
 
         let n: u64 = have_cards.iter().sum();
-        Ok(n.to_string())
+        Ok(n.into())
     }
 }
 
@@ -107,7 +88,7 @@ Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
 "#;
-        many1(terminated(parse_card, line_ending))(s).unwrap().1
+        Solution::parse(s).unwrap()
     }
 
     #[test]
@@ -115,30 +96,13 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
         let cards = parse();
 
         let scores: Vec<_> = cards.iter().map(|c| c.score()).collect();
-        assert_eq!(scores, vec![8,2,2,1,0,0])
-
+        assert_eq!(scores, vec![8,2,2,1,0,0]);
+        assert_eq!(Solution::part1(&cards).unwrap(), Answer::UInt(13));
     }
 
     #[test]
     fn p2() {
         let cards = parse();
-
-        let scores: Vec<_> = cards.iter().map(|c| c.matching() as usize).collect();
-        let len = cards.len();
-        let mut have_cards = vec![1u64; len];
-
-        for pos in 0..len {
-            let score = scores[pos];
-            let num = have_cards[pos];
-            if score > 0 {
-                let from = (pos + 1).clamp(0, len);
-                let to = (pos + score + 1).clamp(0, len);
-                have_cards[from..to].iter_mut().for_each(|h| *h += num);
-            }
-        }
-
-        let n: u64 = have_cards.iter().sum();
-        assert_eq!(n, 30);
+        assert_eq!(Solution::part2(&cards).unwrap(), Answer::UInt(30));
     }
 }
-
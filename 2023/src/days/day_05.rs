@@ -1,18 +1,17 @@
 use std::collections::{HashMap, HashSet};
 use std::ops::RangeInclusive;
 
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use nom::{
-    bytes::complete::{tag},
-    character::complete::{alpha1, line_ending, space0, space1},
-    combinator::{map, opt},
-    multi::{fold_many1, many1, separated_list1},
-    sequence::{delimited, preceded, separated_pair, terminated, tuple},
+    bytes::complete::tag,
+    character::complete::{alpha1, line_ending},
+    combinator::map_opt,
+    sequence::{pair, preceded, separated_pair, terminated},
     IResult
 };
 
-use crate::parsers::positive_integer;
-use crate::Problem;
+use crate::parsers::{blank_lines, lines, number_list, parse_all, sections};
+use crate::{Answer, Problem};
 
 #[derive(Default)]
 pub struct Solution {
@@ -68,50 +67,34 @@ impl Map {
 }
 
 fn parse_seeds(s: &str) -> IResult<&str, Vec<u64>> {
-    delimited(tag("seeds: "), separated_list1(space1, positive_integer), line_ending)(s)
-}
-
-fn parse_maplines(s: &str) -> IResult<&str, Vec<Map>> {
-     let rmap = tuple((
-            terminated(positive_integer::<u64>, space1),
-            terminated(positive_integer::<u64>, space1),
-            terminated(positive_integer::<u64>, line_ending),
-     ));
-
-     fold_many1(rmap, Vec::new, |mut acc, (ds, ss, l)| {
-         acc.push(Map { from: ss..=ss+l-1, to: ds..=ds+l-1 });
-         acc
-     })(s)
+    preceded(tag("seeds:"), number_list)(s)
 }
 
 fn parse_map(s: &str) -> IResult<&str, ((String, String), Vec<Map>)> {
-    let (s, (from, to)) = delimited(
-        opt(line_ending),
+    let (s, (from, to)) = terminated(
         separated_pair(alpha1, tag("-to-"), alpha1),
-        terminated(tag(" map:"), line_ending))(s)?;
+        pair(tag(" map:"), line_ending))(s)?;
 
-    
-    let (s, maps) = preceded(opt(line_ending), parse_maplines)(s)?;
+    let range = map_opt(number_list::<_, u64, _>, |r| match r[..] {
+        [ds, ss, l] => Some(Map { from: ss..=ss+l-1, to: ds..=ds+l-1 }),
+        _ => None,
+    });
+    let (s, maps) = lines(range)(s)?;
 
     Ok((s, ((from.to_owned(), to.to_owned()), maps)))
 }
 
 impl Problem for Solution {
     fn parse(&mut self, s: &str) -> Result<()> {
-        let (s, seeds) = parse_seeds(s).map_err(|e| anyhow!("Parse error {e}"))?;
-
-        let (_s, maps) = fold_many1(parse_map, HashMap::new, |mut acc, (fromto, m)| {
-            acc.insert(fromto, m);
-            acc
-        })(s).map_err(|e| anyhow!("Parse error {e}"))?;
+        let (seeds, maps) = parse_all(s, separated_pair(parse_seeds, blank_lines, sections(parse_map)))?;
 
         self.seeds = seeds;
-        self.maps = maps;
+        self.maps = maps.into_iter().collect();
 
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let order = ["seed","soil","fertilizer","water","light","temperature", "humidity", "location"];
 
         let min = self.seeds.iter().copied().map(|mut id| {
@@ -128,10 +111,10 @@ impl Problem for Solution {
             id
         }).min().unwrap();
 
-        Ok(min.to_string())
+        Ok(min.into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         let seedranges: Vec<RangeInclusive<u64>> = self.seeds.chunks_exact(2).map(|v| {
             v[0]..=v[0]+v[1]-1
         }).collect();
@@ -175,7 +158,7 @@ impl Problem for Solution {
             min = min.min(search.iter().map(|r| *r.start()).min().unwrap());
         }
 
-        Ok(min.to_string())
+        Ok(min.into())
     }
 }
 
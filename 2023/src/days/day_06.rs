@@ -1,5 +1,5 @@
 use std::ops::Range;
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 
 use nom::{
     bytes::complete::{tag},
@@ -9,8 +9,8 @@ use nom::{
     IResult
 };
 
-use crate::parsers::positive_integer;
-use crate::Problem;
+use crate::parsers::{positive_integer, ParseError};
+use crate::{Answer, Problem};
 
 #[derive(Default)]
 pub struct Solution {
@@ -58,24 +58,24 @@ fn parse(s: &str) -> IResult<&str, Vec<Race>> {
 
 impl Problem for Solution {
     fn parse(&mut self, s: &str) -> Result<()> {
-        let (s, races) = parse(s).map_err(|e| anyhow!("Error: {:?}", e))?;
+        let (s, races) = parse(s).map_err(|e| ParseError::new(s, e))?;
 
         self.races = races;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         Ok(self.races.iter()
            .map(|r| r.optimal_solutions().count())
-           .product::<usize>().to_string())
+           .product::<usize>().into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         let time = self.races.iter().map(|r| r.time.to_string()).collect::<String>().parse::<u64>().unwrap();
         let distance = self.races.iter().map(|r| r.distance.to_string()).collect::<String>().parse::<u64>().unwrap();
 
         let new_race = Race { time, distance };
-        Ok(new_race.optimal_solutions().count().to_string())
+        Ok(new_race.optimal_solutions().count().into())
     }
 }
 
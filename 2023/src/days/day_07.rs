@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use nom::{
     character::complete::{anychar, line_ending, space1},
     multi::{many_m_n, separated_list1},
@@ -7,8 +7,8 @@ use nom::{
     IResult
 };
 
-use crate::parsers::positive_integer;
-use crate::Problem;
+use crate::parsers::{parse_all, positive_integer};
+use crate::{Answer, Problem};
 
 #[derive(Default)]
 pub struct Solution {
@@ -145,13 +145,12 @@ fn card_value(c: &char, joker: bool) -> u64 {
 
 impl Problem for Solution {
     fn parse(&mut self, s: &str) -> Result<()> {
-        let (_s, games) = separated_list1(line_ending, parse_game)(s).map_err(|e| anyhow!("parse error: {}", e))?;
-        self.hands = games;
+        self.hands = parse_all(s, separated_list1(line_ending, parse_game))?;
 
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let hands = self.sort_hands(false);
         let sum:u64 = hands.into_iter()
             .enumerate()
@@ -159,10 +158,10 @@ impl Problem for Solution {
                 (i as u64 + 1) * hand.bid
             }).sum();
 
-        Ok(sum.to_string())
+        Ok(sum.into())
     }
 
-   fn part2(&self) -> Result<String> {
+   fn part2(&self) -> Result<Answer> {
         let hands = self.sort_hands(true);
         let sum:u64 = hands.into_iter()
             .enumerate()
@@ -170,7 +169,7 @@ impl Problem for Solution {
                 (i as u64 + 1) * hand.bid
             }).sum();
 
-        Ok(sum.to_string())
+        Ok(sum.into())
    }
 }
 
@@ -187,7 +186,9 @@ KK677 28
 KTJJT 220
 QQQJA 483
 "#;
-        let (_s, hands) = separated_list1(line_ending, parse_game)(s).map_err(|e| anyhow!("parse error: {}", e)).unwrap();
+        let mut solution = Solution::default();
+        solution.parse(s).unwrap();
+        let hands = &solution.hands;
         assert_eq!(hands[0].value, 2);
         assert_eq!(hands[1].value, 4);
         assert_eq!(hands[2].value, 3);
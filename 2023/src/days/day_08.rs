@@ -1,15 +1,16 @@
 use std::collections::{HashSet, HashMap};
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 
 use nom::{
     bytes::complete::{tag},
     character::complete::{anychar, alpha1, line_ending, one_of},
     multi::fold_many1,
-    sequence::{delimited, preceded, separated_pair, terminated},
+    sequence::{delimited, pair, preceded, separated_pair, terminated},
     IResult
 };
 
-use crate::Problem;
+use crate::parsers::parse_all;
+use crate::{Answer, Problem};
 
 #[derive(Default)]
 pub struct Solution {
@@ -98,14 +99,15 @@ fn parse_mapline(s: &str) -> IResult<&str, (String, (String, String))> {
 
 impl Problem for Solution {
     fn parse(&mut self, s: &str) -> Result<()> {
-        let (s, directions) = terminated(parse_directions, line_ending)(s).map_err(|e| anyhow!("parse error: {e}"))?;
-        let (_s, map) = fold_many1(
-            terminated(parse_mapline, line_ending),
-            HashMap::new,
-            |mut acc, (id, lr)| {
-                acc.insert(id, lr);
-                acc
-            })(s).map_err(|e| anyhow!("parse error: {e}"))?;
+        let (directions, map) = parse_all(s, pair(
+            terminated(parse_directions, line_ending),
+            fold_many1(
+                terminated(parse_mapline, line_ending),
+                HashMap::new,
+                |mut acc, (id, lr)| {
+                    acc.insert(id, lr);
+                    acc
+                })))?;
 
         self.directions = directions;
         self.map = map;
@@ -115,7 +117,7 @@ impl Problem for Solution {
 
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let mut map = self.map.get("AAA").unwrap();
 
         let mut count = 0;
@@ -140,10 +142,10 @@ impl Problem for Solution {
         }
         count += 1;
 
-        Ok(count.to_string())
+        Ok(count.into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
 
         // for every start calc the length to the end
         let l: Vec<usize> = self.map.iter().filter_map(|(k, v)| {
@@ -157,7 +159,7 @@ impl Problem for Solution {
         // calculate the first common multiple of all those numbers
         let count = lcm(&l);
 
-        Ok(count.to_string())
+        Ok(count.into())
     }
 }
 
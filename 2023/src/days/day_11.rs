@@ -1,18 +1,9 @@
 use anyhow::{anyhow, Result};
-use nom::{
-    branch::alt,
-    bytes::complete::is_a,
-    character::complete::line_ending,
-    multi::fold_many1,
-    combinator::{all_consuming, eof, map},
-    sequence::terminated,
-    IResult
-};
 use itertools::Itertools;
 
 use crate::grid::{Grid, Coord};
 
-use crate::Problem;
+use crate::{Answer, Problem};
 
 #[derive(Default)]
 pub struct Solution {
@@ -53,39 +44,34 @@ impl Solution {
 }
 
 // from 2020
-fn parse(s: &str) -> IResult<&str, Grid<u8>> {
-    let gridline = terminated(is_a("#."), alt((line_ending, eof)));
-    let grid = fold_many1(gridline, || (Vec::new(), 0usize, 0usize), |(mut v, _x, y), line: &str| {
-        //dimnension checks?
-        let x = line.len();
-        v.extend(line.as_bytes().iter().copied());
-        (v, x, y+1)
-    });
-    map(all_consuming(grid), |(elements, dim_x, dim_y)| Grid::new(elements, dim_x, dim_y))(s)
+fn parse(s: &str) -> Result<Grid<u8>> {
+    Grid::from_str_with(s, |c| match c {
+        '#' | '.' => Ok(c as u8),
+        _ => Err(anyhow!("Expected # or .")),
+    })
 }
 
 
 
 impl Problem for Solution {
     fn parse(&mut self, s: &str) -> Result<()> {
-        let (s, result) = parse(s).map_err(|e| anyhow!("parse error: {e}"))?;
-        self.grid = result;
+        self.grid = parse(s)?;
         Ok(())
 
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let paired_lengths:usize = self.paired_lengths(1)
             .into_iter()
             .sum();
-        Ok(paired_lengths.to_string())
+        Ok(paired_lengths.into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         let paired_lengths:usize = self.paired_lengths(999_999)
             .into_iter()
             .sum();
-        Ok(paired_lengths.to_string())
+        Ok(paired_lengths.into())
     }
 }
 
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 use std::fmt;
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use nom::{
     bytes::complete::tag,
     character::complete::{line_ending, one_of, space1},
@@ -10,8 +10,8 @@ use nom::{
     IResult
 };
 
-use crate::parsers::positive_integer;
-use crate::Problem;
+use crate::parsers::{parse_all, positive_integer};
+use crate::{Answer, Problem};
 
 #[derive(Default)]
 pub struct Solution {
@@ -106,20 +106,15 @@ fn parse_line(s: &str) -> IResult<&str, Springs> {
 
 impl Problem for Solution {
     fn parse(&mut self, s: &str) -> Result<()> {
-        let (_s, springs) = many1(parse_line)(s)
-            .map_err(|e| anyhow!("parse error: {e}"))?;
-
-        //dbg!(&springs);
-        eprintln!("max: {:?}", springs.iter().map(|s| s.data.len()).max());
-        self.data = springs;
+        self.data = parse_all(s, many1(parse_line))?;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
-        Ok(self.data.iter().map(|s| s.count_arrangements()).sum::<usize>().to_string())
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.data.iter().map(|s| s.count_arrangements()).sum::<usize>().into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         // stick 5 together
         let newsprings:Vec<_> = self.data.iter().map(|s| {
             let mut springs:Vec<_> = (0..5).fold(Vec::new(), |mut acc, _i| {
@@ -137,7 +132,7 @@ impl Problem for Solution {
 
         }).collect();
 
-        Ok(newsprings.iter().map(|s| s.count_arrangements()).sum::<usize>().to_string())
+        Ok(newsprings.iter().map(|s| s.count_arrangements()).sum::<usize>().into())
     }
 }
 
@@ -1,5 +1,5 @@
 use std::fmt;
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use nom::{
     bytes::complete::is_a,
     character::complete::line_ending,
@@ -9,7 +9,8 @@ use nom::{
     IResult
 };
 
-use crate::Problem;
+use crate::parsers::ParseError;
+use crate::{Answer, Problem};
 
 #[derive(Default)]
 pub struct Solution {
@@ -128,16 +129,15 @@ fn parse_input(s: &str) -> IResult<&str, Vec<Pattern>> {
 
 impl Problem for Solution {
     fn parse(&mut self, s: &str) -> Result<()> {
-        let (s, patterns) = all_consuming(terminated(parse_input, eof))(s)
-            .map_err(|e| anyhow!("parse error: {:?}", e))?;
-        dbg!(s);
+        let (_, patterns) = all_consuming(terminated(parse_input, eof))(s)
+            .map_err(|e| ParseError::new(s, e))?;
 
         self.patterns = patterns;
 
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         Ok(self.patterns.iter().filter_map(|p| {
             let mr = p.find_mirror_row(0);
             let mc = p.find_mirror_col(0);
@@ -147,10 +147,10 @@ impl Problem for Solution {
                 (Some(x), Some(y)) => panic!("both found?: {} {}", x, y),
                 (None, None) => panic!("neither found"),
             }
-        }).sum::<usize>().to_string())
+        }).sum::<usize>().into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         Ok(self.patterns.iter().filter_map(|p| {
             let mr = p.find_mirror_row(1);
             let mc = p.find_mirror_col(1);
@@ -160,7 +160,7 @@ impl Problem for Solution {
                 (Some(x), Some(y)) => panic!("both found?: {} {}", x, y),
                 (None, None) => panic!("neither found"),
             }
-        }).sum::<usize>().to_string())
+        }).sum::<usize>().into())
     }
 }
 
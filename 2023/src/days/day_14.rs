@@ -1,18 +1,9 @@
 use std::collections::HashMap;
 use std::fmt;
 use anyhow::{anyhow, Result};
-use nom::{
-    branch::alt,
-    bytes::complete::is_a,
-    character::complete::line_ending,
-    multi::fold_many1,
-    combinator::{all_consuming, eof, map},
-    sequence::terminated,
-    IResult
-};
-
-
-use crate::Problem;
+
+
+use crate::{Answer, Problem};
 use crate::grid::{Grid, Coord};
 
 #[derive(Default)]
@@ -128,6 +119,14 @@ impl Platform {
         }
     }
 
+    /// Tilts north, west, south and east
+    fn spin(&mut self) {
+        self.tilt_up();
+        self.tilt_left();
+        self.tilt_down();
+        self.tilt_right();
+    }
+
     fn load(&self) -> usize {
         let nrow = self.rocks.dim_y;
         (0..self.rocks.dim_y)
@@ -136,40 +135,33 @@ impl Platform {
     }
 }
 
-fn parse_grid(s: &str) -> IResult<&str, Grid<Rock>> {
-    let gridline = terminated(is_a(".O#"), alt((line_ending, eof)));
-    let grid = fold_many1(gridline, || (Vec::new(), 0usize, 0usize), |(mut v, _x, y), line: &str| {
-        //dimnension checks?
-        let x = line.len();
-        v.extend(line.chars().map(|c| match c {
-            '#' => Rock::Fixed,
-            'O' => Rock::Moving,
-            '.' => Rock::NoRock,
-            _ => unreachable!(),
-        }));
-        (v, x, y+1)
-    });
-    map(all_consuming(grid), |(elements, dim_x, dim_y)| Grid::new(elements, dim_x, dim_y))(s)
+fn parse_grid(s: &str) -> Result<Grid<Rock>> {
+    Grid::from_str_with(s, |c| match c {
+        '#' => Ok(Rock::Fixed),
+        'O' => Ok(Rock::Moving),
+        '.' => Ok(Rock::NoRock),
+        _ => Err(anyhow!("Expected #, O or .")),
+    })
 }
 
 
 impl Problem for Solution {
     fn parse(&mut self, s: &str) -> Result<()> {
-        let (_s, grid) = parse_grid(s).map_err(|e| anyhow!("parse error: {e}"))?;
+        let grid = parse_grid(s)?;
         self.platform = Platform { rocks: grid };
         Ok(())
 
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let mut platform = self.platform.clone();
 
         platform.tilt_up();
 
-        Ok(platform.load().to_string())
+        Ok(platform.load().into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
 
         let mut platform = self.platform.clone();
 
@@ -178,10 +170,7 @@ impl Problem for Solution {
         let mut todo = None;
 
         loop {
-            platform.tilt_up();
-            platform.tilt_left();
-            platform.tilt_down();
-            platform.tilt_right();
+            platform.spin();
             it += 1;
             let load = platform.load();
             if let Some(prev_it) = load_cache.get(&platform.rocks.elements) {
@@ -197,7 +186,7 @@ impl Problem for Solution {
 
             if let Some(mut len) = todo {
                 if len == 0 {
-                    return Ok(load.to_string());
+                    return Ok(load.into());
                 } else {
                     len -= 1;
                     todo = Some(len);
@@ -1,4 +1,4 @@
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use nom::{
     branch::alt,
     bytes::complete::{tag, is_not},
@@ -10,7 +10,8 @@ use nom::{
 };
 
 
-use crate::Problem;
+use crate::parsers::parse_all;
+use crate::{Answer, Problem};
 
 #[derive(Default)]
 pub struct Solution {
@@ -50,19 +51,16 @@ fn parse_instruction(s: &str) -> IResult<&str, Command> {
 
 impl Problem for Solution {
     fn parse(&mut self, s: &str) -> Result<()> {
-        let (_s,  data) = terminated(parse_line, line_ending)(s)
-            .map_err(|e| anyhow!("parse error: {e}"))?;
-
-        self.data = data;
+        self.data = parse_all(s, terminated(parse_line, line_ending))?;
         Ok(())
 
     }
 
-    fn part1(&self) -> Result<String> {
-        Ok(self.data.iter().map(|s| hash(s)).sum::<usize>().to_string())
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.data.iter().map(|s| hash(s)).sum::<usize>().into())
     }
 
-   fn part2(&self) -> Result<String> {
+   fn part2(&self) -> Result<Answer> {
        let commands: Vec<Command>  = self.data.iter()
            .map(|s| parse_instruction(s.as_str()).unwrap().1)
            .collect();
@@ -97,7 +95,7 @@ impl Problem for Solution {
                    .sum::<u64>()
            }).sum::<u64>();
 
-       Ok(sum.to_string())
+       Ok(sum.into())
    }
 }
 
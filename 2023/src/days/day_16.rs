@@ -1,16 +1,7 @@
 use anyhow::{anyhow, Result};
-use nom::{
-    branch::alt,
-    bytes::complete::is_a,
-    character::complete::line_ending,
-    multi::fold_many1,
-    combinator::{all_consuming, eof, map},
-    sequence::terminated,
-    IResult
-};
-
-
-use crate::Problem;
+
+
+use crate::{Answer, Problem};
 use crate::grid::{Grid, Coord};
 
 #[derive(Default)]
@@ -152,34 +143,27 @@ impl Direction {
     }
 }
 
-fn parse_grid(s: &str) -> IResult<&str, Grid<Mirror>> {
-    let gridline = terminated(is_a(".|-/\\"), alt((line_ending, eof)));
-    let grid = fold_many1(gridline, || (Vec::new(), 0usize, 0usize), |(mut v, _x, y), line: &str| {
-        //dimnension checks?
-        let x = line.len();
-        v.extend(line.chars().map(|c| match c {
-            '.' => Mirror::NoMirror,
-            '|' => Mirror::Vertical,
-            '-' => Mirror::Horizontal,
-            '\\' => Mirror::Diagonal,
-            '/' => Mirror::Antidiagonal,
-            _ => unreachable!(),
-        }));
-        (v, x, y+1)
-    });
-    map(all_consuming(grid), |(elements, dim_x, dim_y)| Grid::new(elements, dim_x, dim_y))(s)
+fn parse_grid(s: &str) -> Result<Grid<Mirror>> {
+    Grid::from_str_with(s, |c| match c {
+        '.' => Ok(Mirror::NoMirror),
+        '|' => Ok(Mirror::Vertical),
+        '-' => Ok(Mirror::Horizontal),
+        '\\' => Ok(Mirror::Diagonal),
+        '/' => Ok(Mirror::Antidiagonal),
+        _ => Err(anyhow!("Expected a mirror or .")),
+    })
 }
 
 
 
 impl Problem for Solution {
     fn parse(&mut self, s: &str) -> Result<()> {
-        let (_s, grid) = parse_grid(s).map_err(|e| anyhow!("parse error: {e}"))?;
+        let grid = parse_grid(s)?;
         self.grid = grid;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let beam = Beam::new(-1, 0, Direction::Right);
         let mut beams = vec![beam];
         let mut newbeams = Vec::new();
@@ -214,10 +198,10 @@ impl Problem for Solution {
             std::mem::swap(&mut beams, &mut newbeams);
         }
 
-        Ok(seen_directions.iter().filter(|&&b| b != 0).count().to_string())
+        Ok(seen_directions.iter().filter(|&&b| b != 0).count().into())
     }
 
-   fn part2(&self) -> Result<String> {
+   fn part2(&self) -> Result<Answer> {
         // test beams from all the edges
         let starts: Vec<Beam> = (0..self.grid.dim_x as isize).map(|x| Beam::new(x, -1, Direction::Down))
             .chain((0..self.grid.dim_x as isize).map(|x| Beam::new(x, self.grid.dim_y as isize, Direction::Up)))
@@ -265,7 +249,7 @@ impl Problem for Solution {
 
         }).max().unwrap();
 
-        Ok(max.to_string())
+        Ok(max.into())
 
 
    }
@@ -1,17 +1,16 @@
 use std::collections::HashMap;
 use std::ops::RangeInclusive;
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use nom::{
-    IResult,
-    bytes::complete::tag,
-    character::complete::{alpha1, line_ending, one_of},
-    combinator::{map, opt, peek},
-    multi::{fold_many1, many1, separated_list1},
-    sequence::{delimited, preceded, separated_pair, terminated, tuple},
+    character::complete::line_ending,
+    multi::{many1, separated_list1},
+    sequence::{separated_pair, terminated},
 };
 
-use crate::parsers::positive_integer;
-use crate::Problem;
+use aoc_core::record;
+use crate::parsers::parse_all;
+use crate::parsers::record::FromField;
+use crate::{Answer, Problem};
 
 #[derive(Default)]
 pub struct Solution {
@@ -136,67 +135,53 @@ impl Part {
     }
 }
 
-fn parse_rule(s: &str) -> IResult<&str, Rule> {
-    // peek the rating type and the operator togeter to avoid parsing part of the
-    // default workflow name
-    peek(tuple((one_of("xmas"), one_of("><"))))(s)?;
-    map(tuple((one_of("xmas"), one_of("><"), positive_integer, preceded(tag(":"), alpha1))),
-    |(rating_type, condition, value, destination)| {
-        Rule {
-            rating_type: match rating_type {
-                'x' => RatingType::Cool,
-                'm' => RatingType::Musical,
-                'a' => RatingType::Aerodynamic,
-                's' => RatingType::Shiny,
-                _ => panic!("Invalid part type"),
-            },
-            condition: match condition {
-                '<' => Condition::LessThan(value),
-                '>' => Condition::GreaterThan(value),
-                _ => panic!("Invalid condition"),
-            },
-            target_workflow: destination.to_string(),
-        }
-    })(s)
-}
-
-fn parse_workflow(s: &str) -> IResult<&str, (String, Workflow)> {
-    let rules = separated_list1(tag(","), parse_rule);
-    let default_target = preceded(tag(","), alpha1);
-    map(terminated(tuple((alpha1, delimited(tag("{"), tuple((rules, default_target)), tag("}")))), line_ending),
-        |(name, (rules, default_target))| (name.to_string(), Workflow { rules, default_target: default_target.to_string() }))(s)
+/// A rule like `a<2006:qkq`
+fn parse_rule(s: &str) -> Option<Rule> {
+    let mut chars = s.chars();
+    let rating_type = match chars.next()? {
+        'x' => RatingType::Cool,
+        'm' => RatingType::Musical,
+        'a' => RatingType::Aerodynamic,
+        's' => RatingType::Shiny,
+        _ => return None,
+    };
+    let op = chars.next()?;
+    let (value, target) = chars.as_str().split_once(':')?;
+    let value = value.parse().ok()?;
+    let condition = match op {
+        '<' => Condition::LessThan(value),
+        '>' => Condition::GreaterThan(value),
+        _ => return None,
+    };
+    Some(Rule { rating_type, condition, target_workflow: target.to_string() })
 }
 
-fn parse_parts(s: &str) -> IResult<&str, Part> {
-    let parse_part = fold_many1(terminated(separated_pair(one_of("xmas"), tag("="), positive_integer), opt(tag(","))),
-    Part::default,
-    |mut part, (part_type, value)| 
-    {
-        match part_type {
-            'x' => part.values[RatingType::Cool as usize] = value,
-            'm' => part.values[RatingType::Musical as usize] = value,
-            'a' => part.values[RatingType::Aerodynamic as usize] = value,
-            's' => part.values[RatingType::Shiny as usize] = value,
-            _ => panic!("Invalid part type"),
-        }
-        part
-    });
-
-    delimited(tag("{"),  parse_part, tag("}"))(s)
+/// The rules of a workflow followed by the default target, like `a<2006:qkq,m>2090:A,rfg`
+impl FromField for Workflow {
+    fn from_field(s: &str) -> Option<Workflow> {
+        let (rules, default_target) = s.rsplit_once(',')?;
+        let rules = rules.split(',').map(parse_rule).collect::<Option<_>>()?;
+        Some(Workflow { rules, default_target: default_target.to_string() })
+    }
 }
 
 impl Problem for Solution {
     fn parse(&mut self, s: &str) -> Result<()> {
-        let (s, workflows) = many1(parse_workflow)(s)
-            .map_err(|e| anyhow!("Parser error {e}"))?;
-        let (_s, parts) = preceded(line_ending, separated_list1(line_ending, parse_parts))(s)
-            .map_err(|e| anyhow!("Parser error {e}"))?;
+        let workflow = record!("{name:word}{{{rules}}}", |name: String, rules: Workflow| (name, rules));
+        let part = record!("{{x={x:int},m={m:int},a={a:int},s={s:int}}}", |x: u32, m: u32, a: u32, s: u32| {
+            Part { values: [x, m, a, s] }
+        });
+        let (workflows, parts) = parse_all(s, separated_pair(
+            many1(terminated(workflow, line_ending)),
+            line_ending,
+            separated_list1(line_ending, part),
+        ))?;
         self.workflows = workflows.into_iter().collect();
         self.parts = parts;
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         // count accepted parts
         Ok(self.parts.iter().filter_map(|part| {
             let mut workflow = self.workflows.get("in").unwrap();
@@ -210,10 +195,10 @@ impl Problem for Solution {
                     workflow = self.workflows.get(target).unwrap();
                 }   
             }
-        }).map(|p| p.sum()).sum::<u32>().to_string())
+        }).map(|p| p.sum()).sum::<u32>().into())
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
 
         let start = RangeSet {
             target: "in".to_owned(),
@@ -264,7 +249,7 @@ impl Problem for Solution {
             }));
         }
 
-        Ok(accepted.iter().map(|r| r.sum()).sum::<u64>().to_string())
+        Ok(accepted.iter().map(|r| r.sum()).sum::<u64>().into())
     }
 }
 
@@ -1,17 +1,18 @@
 use std::collections::{HashMap, VecDeque};
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use nom::{
-    IResult,
     bytes::complete::tag,
     branch::alt,
-    character::complete::{alpha1, line_ending, one_of},
-    combinator::{map, opt, peek},
-    multi::{fold_many1, many1, separated_list1},
-    sequence::{delimited, preceded, separated_pair, terminated, tuple},
+    character::complete::{alpha1, line_ending},
+    combinator::{cut, map},
+    error::context,
+    multi::{fold_many1, separated_list1},
+    sequence::{pair, preceded, separated_pair, terminated},
 };
 
 
-use crate::Problem;
+use crate::parsers::{parse_all, VResult};
+use crate::{Answer, Problem};
 
 #[derive(Default)]
 pub struct Solution {
@@ -120,51 +121,53 @@ impl Module {
 }
 
 
-fn parse_module(s: &str) -> IResult<&str, (String, Module)> {
-    let broacaster = map(separated_pair(tag("broadcaster"), tag(" -> "), separated_list1(tag(", "), alpha1)),
-    |(name, outputs): (&str, Vec<&str>)| {
-        let mut broadcaster = Broadcaster::default();
-        broadcaster.outputs = outputs.iter().map(|s| s.to_string()).collect();
+fn outputs(s: &str) -> VResult<'_, Vec<String>> {
+    context("outputs", map(separated_list1(tag(", "), alpha1), |outputs: Vec<&str>| {
+        outputs.iter().map(|s| s.to_string()).collect()
+    }))(s)
+}
+
+// after the type prefix the line has to be a module, cut reports errors in the line itself
+fn parse_module(s: &str) -> VResult<'_, (String, Module)> {
+    let broacaster = map(pair(tag("broadcaster"), cut(preceded(tag(" -> "), outputs))),
+    |(name, outputs): (&str, Vec<String>)| {
+        let broadcaster = Broadcaster { outputs };
         (name.to_owned(), Module::Broadcaster(broadcaster))
     });
 
-    let flip_flop = map(separated_pair(preceded(tag("%"), alpha1), tag(" -> "), separated_list1(tag(", "), alpha1)),
-    |(name, outputs): (&str, Vec<&str>)| {
-        let mut flip_flop = FlipFlop::default();
-        flip_flop.outputs = outputs.iter().map(|s| s.to_string()).collect();
+    let flip_flop = map(preceded(tag("%"), cut(separated_pair(alpha1, tag(" -> "), outputs))),
+    |(name, outputs): (&str, Vec<String>)| {
+        let flip_flop = FlipFlop { outputs, ..Default::default() };
         (name.to_owned(), Module::FlipFlop(flip_flop))
     });
 
-    let conjunction = map(separated_pair(preceded(tag("&"), alpha1), tag(" -> "), separated_list1(tag(", "), alpha1)),
-    |(name, outputs): (&str, Vec<&str>)| {
-        let mut conjunction = Conjunction::default();
-        
-        conjunction.outputs = outputs.iter().map(|s| s.to_string()).collect();
+    let conjunction = map(preceded(tag("&"), cut(separated_pair(alpha1, tag(" -> "), outputs))),
+    |(name, outputs): (&str, Vec<String>)| {
+        let conjunction = Conjunction { outputs, ..Default::default() };
         (name.to_owned(), Module::Conjunction(conjunction))
     });
 
-
-    alt((broacaster, flip_flop, conjunction))(s)
+    context("module", alt((broacaster, flip_flop, conjunction)))(s)
 }
 
 
 impl Problem for Solution {
     fn parse(&mut self, s: &str) -> Result<()> {
-        let (_s, modules) = fold_many1(
+        let modules = parse_all(s, fold_many1(
             terminated(parse_module, line_ending),
             HashMap::new,
             |mut modules, (name, module)| {
                 modules.insert(name, module);
                 modules
             },
-        )(s).map_err(|e| anyhow!("parse error: {:?}", e))?;
+        ))?;
 
         self.modules = modules;
 
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
         let mut modules = self.modules.clone();
 
         // initialize the inputs for the conjunctions
@@ -211,11 +214,11 @@ impl Problem for Solution {
             }
         }
 
-        Ok(pulse_counts.iter().product::<u64>().to_string())
+        Ok(pulse_counts.iter().product::<u64>().into())
 
     }
 
-   fn part2(&self) -> Result<String> {
+   fn part2(&self) -> Result<Answer> {
         let mut modules = self.modules.clone();
 
         // initialize the inputs for the conjunctions
@@ -286,7 +289,7 @@ impl Problem for Solution {
             }
         }
 
-        Ok(rep.iter().skip(1).fold(rep[0], |acc, &v| num::integer::lcm(acc, v)).to_string())
+        Ok(rep.iter().skip(1).fold(rep[0], |acc, &v| num::integer::lcm(acc, v)).into())
 
    }
 }
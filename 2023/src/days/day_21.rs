@@ -1,7 +1,8 @@
 #[allow(unused_imports)]
 use anyhow::{anyhow, Result};
 
-use crate::Problem;
+#[allow(unused_imports)]
+use crate::{Answer, Problem};
 
 #[derive(Default)]
 pub struct Solution {
@@ -12,10 +13,10 @@ impl Problem for Solution {
     fn parse(&mut self, i: &[u8]) -> Result<()> {
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
     }
 
-   fn part2(&self) -> Result<String> {
+   fn part2(&self) -> Result<Answer> {
    }
     */
 }
@@ -11,8 +11,8 @@ use nom::{
 };
 
 
-use crate::Problem;
-use crate::parsers::positive_integer;
+use crate::{Answer, Problem};
+use crate::parsers::{parse_all, positive_integer};
 
 #[derive(Default)]
 pub struct Solution {
@@ -137,8 +137,7 @@ fn parse_block(s: &str) -> IResult<&str, Block> {
 
 impl Problem for Solution {
     fn parse(&mut self, s: &str) -> Result<()> {
-        let (_s, mut data) = separated_list1(line_ending, parse_block)(s)
-            .map_err(|e| anyhow!("parse error: {e}"))?;
+        let mut data = parse_all(s, separated_list1(line_ending, parse_block))?;
         // assign ids
         data.iter_mut().enumerate().for_each(|(i, b)| b.id = i + 1);
 
@@ -146,7 +145,7 @@ impl Problem for Solution {
         Ok(())
     }
 
-    fn part1(&self) -> Result<String> {
+    fn part1(&self) -> Result<Answer> {
 
         let mut blocks = self.data.clone();
         //get the dimensions of the space
@@ -168,11 +167,11 @@ impl Problem for Solution {
 
         fall_blocks(&mut column, &mut blocks);
 
-        Ok((0..blocks.len()).filter(|idx| can_delete(&column, &blocks, *idx)).count().to_string())
+        Ok((0..blocks.len()).filter(|idx| can_delete(&column, &blocks, *idx)).count().into())
 
     }
 
-    fn part2(&self) -> Result<String> {
+    fn part2(&self) -> Result<Answer> {
         let mut blocks = self.data.clone();
         //get the dimensions of the space
         let max_x = blocks.iter().map(|b| b.x1.max(b.x2)).max().unwrap();
@@ -208,7 +207,7 @@ impl Problem for Solution {
                 }
             }
             fall_blocks(&mut column, &mut blocks)
-        }).sum::<usize>().to_string())
+        }).sum::<usize>().into())
 
     }
 }
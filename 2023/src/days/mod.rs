@@ -27,15 +27,15 @@ mod day_23;
 mod day_24;
 mod day_25;
 
-use crate::Problem;
+use crate::{Parsed, Problem};
 
 #[allow(clippy::box_default)]
 pub fn get_solution(d: u32) -> Result<Box<dyn Problem>> {
     match d {
         1 => Ok(Box::<day_01::Solution>::default()),
-        2 => Ok(Box::<day_02::Solution>::default()),
+        2 => Ok(Box::<Parsed<day_02::Solution>>::default()),
         3 => Ok(Box::<day_03::Solution>::default()),
-        4 => Ok(Box::<day_04::Solution>::default()),
+        4 => Ok(Box::<Parsed<day_04::Solution>>::default()),
         5 => Ok(Box::<day_05::Solution>::default()),
         6 => Ok(Box::<day_06::Solution>::default()),
         7 => Ok(Box::<day_07::Solution>::default()),
@@ -0,0 +1,125 @@
+use serde::Serialize;
+
+/// Allocations made during a phase. `peak` is the highest number of live bytes on top of what was
+/// already allocated when the phase started.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct AllocStats {
+    pub count: u64,
+    pub bytes: u64,
+    pub peak: u64,
+}
+
+/// Whether allocations are counted. The binary has to install [`Counting`] as its global
+/// allocator for the counters to move.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+#[cfg(feature = "alloc-stats")]
+pub use counting::Counting;
+
+/// Runs `f` and returns the allocations it made. Without the `alloc-stats` feature nothing is
+/// counted. The counters are global, so allocations on other threads are included.
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let start = counting::start();
+        let v = f();
+        (v, Some(counting::since(start)))
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    (f(), None)
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
+
+    use super::AllocStats;
+
+    static COUNT: AtomicU64 = AtomicU64::new(0);
+    static BYTES: AtomicU64 = AtomicU64::new(0);
+    static LIVE: AtomicU64 = AtomicU64::new(0);
+    static PEAK: AtomicU64 = AtomicU64::new(0);
+
+    /// Allocator that counts allocations on top of the system allocator
+    pub struct Counting;
+
+    fn grow(size: usize) {
+        COUNT.fetch_add(1, Relaxed);
+        BYTES.fetch_add(size as u64, Relaxed);
+        let live = LIVE.fetch_add(size as u64, Relaxed) + size as u64;
+        PEAK.fetch_max(live, Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let p = System.alloc(layout);
+            if !p.is_null() {
+                grow(layout.size());
+            }
+            p
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let p = System.alloc_zeroed(layout);
+            if !p.is_null() {
+                grow(layout.size());
+            }
+            p
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            LIVE.fetch_sub(layout.size() as u64, Relaxed);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let p = System.realloc(ptr, layout, new_size);
+            if !p.is_null() {
+                // counted as a new allocation that replaces the old one
+                LIVE.fetch_sub(layout.size() as u64, Relaxed);
+                grow(new_size);
+            }
+            p
+        }
+    }
+
+    pub struct Start {
+        count: u64,
+        bytes: u64,
+        live: u64,
+    }
+
+    pub fn start() -> Start {
+        let live = LIVE.load(Relaxed);
+        PEAK.store(live, Relaxed);
+        Start { count: COUNT.load(Relaxed), bytes: BYTES.load(Relaxed), live }
+    }
+
+    pub fn since(start: Start) -> AllocStats {
+        AllocStats {
+            count: COUNT.load(Relaxed) - start.count,
+            bytes: BYTES.load(Relaxed) - start.bytes,
+            peak: PEAK.load(Relaxed).saturating_sub(start.live),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc-stats"))]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    #[test]
+    fn counts() {
+        let (v, stats) = measure(|| vec![0u8; 1000].len());
+        assert_eq!(v, 1000);
+        let stats = stats.unwrap();
+        assert!(stats.count >= 1);
+        assert!(stats.bytes >= 1000);
+        assert!(stats.peak >= 1000);
+    }
+}
@@ -0,0 +1,234 @@
+use std::fmt;
+use std::fs::{read_to_string, write};
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::{Serialize, Serializer};
+
+use crate::grid::Grid;
+
+/// The answer to a part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Str(String),
+    /// Answers that need to be read from a picture, lit pixels are `true`
+    Bitmap(Grid<bool>),
+}
+
+impl Answer {
+    /// Representation that fits on a single line. Rows of a bitmap are separated by `/`.
+    pub fn to_line(&self) -> String {
+        match self {
+            Answer::Bitmap(g) => bitmap_rows(g).collect::<Vec<_>>().join("/"),
+            a => a.to_string(),
+        }
+    }
+
+    /// Compares the answer with the textual form of a known answer
+    pub fn matches(&self, expected: &str) -> bool {
+        let expected = expected.trim();
+        match self {
+            Answer::Int(n) => expected.parse() == Ok(*n),
+            Answer::UInt(n) => expected.parse() == Ok(*n),
+            Answer::Str(s) => s.trim() == expected,
+            Answer::Bitmap(_) => self.to_line() == expected,
+        }
+    }
+}
+
+fn bitmap_rows(g: &Grid<bool>) -> impl Iterator<Item=String> + '_ {
+    (0..g.dim_y).map(move |y| g.iter_row(y).map(|&b| if b { '#' } else { '.' }).collect())
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::UInt(n) => write!(f, "{}", n),
+            Answer::Str(s) => write!(f, "{}", s),
+            Answer::Bitmap(g) => {
+                for row in bitmap_rows(g) {
+                    writeln!(f, "{}", row)?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+/// Numbers serialize as numbers, bitmaps as a list of rows
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Int(n) => s.serialize_i64(*n),
+            Answer::UInt(n) => s.serialize_u64(*n),
+            Answer::Str(v) => s.serialize_str(v),
+            Answer::Bitmap(g) => s.collect_seq(bitmap_rows(g)),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $target:ty, $($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Answer {
+                Answer::$variant(n as $target)
+            }
+        })*
+    };
+}
+
+answer_from!(Int, i64, i8, i16, i32, i64, isize);
+answer_from!(UInt, u64, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Str(s.to_string())
+    }
+}
+
+impl From<Grid<bool>> for Answer {
+    fn from(g: Grid<bool>) -> Answer {
+        Answer::Bitmap(g)
+    }
+}
+
+/// Known good answers for a day. They are stored next to the input file as `day_NN.answers`,
+/// with the answer to part 1 on the first line and the answer to part 2 on the second line.
+/// An empty line marks an answer as unknown.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Check {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl Check {
+    /// Name in the machine readable output
+    pub fn name(self) -> &'static str {
+        match self {
+            Check::Pass => "pass",
+            Check::Fail => "fail",
+            Check::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Check::Pass => write!(f, "pass"),
+            Check::Fail => write!(f, "FAIL"),
+            Check::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl Answers {
+    /// Location of the answers file that belongs to `input`
+    pub fn path_for<P: AsRef<Path>>(input: P) -> PathBuf {
+        input.as_ref().with_extension("answers")
+    }
+
+    /// Reads the answers file belonging to `input`. A missing file means all answers are unknown.
+    pub fn for_input<P: AsRef<Path>>(input: P) -> Result<Answers> {
+        let path = Answers::path_for(input);
+        if path.exists() {
+            Ok(Answers::parse(&read_to_string(path)?))
+        } else {
+            Ok(Answers::default())
+        }
+    }
+
+    /// Writes the answers file belonging to `input`
+    pub fn save_for_input<P: AsRef<Path>>(&self, input: P) -> Result<()> {
+        let line = |a: &Option<String>| a.clone().unwrap_or_default();
+        write(Answers::path_for(input), format!("{}\n{}\n", line(&self.part1), line(&self.part2)))?;
+        Ok(())
+    }
+
+    pub fn parse(s: &str) -> Answers {
+        let mut lines = s.lines()
+            .map(str::trim)
+            .map(|l| (!l.is_empty()).then(|| l.to_string()));
+
+        Answers {
+            part1: lines.next().flatten(),
+            part2: lines.next().flatten(),
+        }
+    }
+
+    pub fn check_part1(&self, answer: &Answer) -> Check {
+        check(self.part1.as_deref(), answer)
+    }
+
+    pub fn check_part2(&self, answer: &Answer) -> Check {
+        check(self.part2.as_deref(), answer)
+    }
+}
+
+fn check(expected: Option<&str>, answer: &Answer) -> Check {
+    match expected {
+        Some(e) if answer.matches(e) => Check::Pass,
+        Some(_) => Check::Fail,
+        None => Check::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let a = Answers::parse("123\nabc\n");
+        assert_eq!(a.part1.as_deref(), Some("123"));
+        assert_eq!(a.part2.as_deref(), Some("abc"));
+
+        let a = Answers::parse("\n42\n");
+        assert_eq!(a.part1, None);
+        assert_eq!(a.part2.as_deref(), Some("42"));
+
+        let a = Answers::parse("42");
+        assert_eq!(a.part2, None);
+    }
+
+    #[test]
+    fn checks() {
+        let a = Answers::parse("123\n");
+        assert_eq!(a.check_part1(&123u32.into()), Check::Pass);
+        assert_eq!(a.check_part1(&124i64.into()), Check::Fail);
+        assert_eq!(a.check_part1(&"123".into()), Check::Pass);
+        assert_eq!(a.check_part2(&124usize.into()), Check::Unknown);
+    }
+
+    #[test]
+    fn bitmap() {
+        let a = Answer::from(Grid::new(vec![true, false, false, true], 2, 2));
+        assert_eq!(a.to_string(), "#.\n.#\n");
+        assert_eq!(a.to_line(), "#./.#");
+        assert!(a.matches("#./.#"));
+        assert_eq!(serde_json::to_string(&a).unwrap(), r##"["#.",".#"]"##);
+        assert_eq!(serde_json::to_string(&Answer::from(-3)).unwrap(), "-3");
+    }
+
+    #[test]
+    fn path() {
+        assert_eq!(Answers::path_for("inputs/day_01.txt"), PathBuf::from("inputs/day_01.answers"));
+    }
+}
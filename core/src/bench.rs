@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::{read_to_string, write};
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+
+use crate::report::{DayResult, Failure, FailureKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part1"),
+            Phase::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+impl std::str::FromStr for Phase {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Phase> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            "part1" => Ok(Phase::Part1),
+            "part2" => Ok(Phase::Part2),
+            _ => Err(anyhow!("Unknown phase {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "Cannot compute statistics without samples");
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let var = sorted.iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>() / n as f64;
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(var.sqrt()),
+        }
+    }
+}
+
+/// Timing statistics for the phases of a single day. Phases that never finished have no
+/// statistics, the first failure is kept.
+#[derive(Debug, Clone, Default)]
+pub struct DayBench {
+    pub day: u32,
+    pub parse: Option<Stats>,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+    /// A timed run was contended by a worker that timed out, see [`DayResult::contended`]
+    pub contended: bool,
+    pub error: Option<Failure>,
+}
+
+impl DayBench {
+    pub fn phase(&self, phase: Phase) -> Option<&Stats> {
+        match phase {
+            Phase::Parse => self.parse.as_ref(),
+            Phase::Part1 => self.part1.as_ref(),
+            Phase::Part2 => self.part2.as_ref(),
+        }
+    }
+}
+
+/// Runs a day `warmup` times untimed, followed by `iterations` timed runs. `run` solves the day
+/// with a fresh solution instance. A phase that fails keeps failing, the phases before it are
+/// still timed. After a timeout the day is not run again.
+pub fn bench_day<F: FnMut() -> DayResult>(day: u32, iterations: usize, warmup: usize, mut run: F) -> DayBench {
+    let mut bench = DayBench { day, ..Default::default() };
+    let mut samples = [Vec::with_capacity(iterations), Vec::with_capacity(iterations), Vec::with_capacity(iterations)];
+
+    for i in 0..warmup + iterations {
+        let result = run();
+        if i >= warmup {
+            bench.contended |= result.contended;
+            for (samples, time) in samples.iter_mut().zip([result.parse_time, result.part1_time, result.part2_time]) {
+                samples.extend(time);
+            }
+        }
+        if let Some(e) = result.error {
+            let timeout = e.kind == FailureKind::Timeout;
+            bench.error.get_or_insert(e);
+            if timeout {
+                break;
+            }
+        }
+    }
+
+    let stats = |s: &[Duration]| (!s.is_empty()).then(|| Stats::from_samples(s));
+    bench.parse = stats(&samples[0]);
+    bench.part1 = stats(&samples[1]);
+    bench.part2 = stats(&samples[2]);
+    bench
+}
+
+/// Median timings of a previous benchmark run. The file format has a line per day and phase:
+/// `day<TAB>phase<TAB>nanoseconds`
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline(HashMap<(u32, Phase), Duration>);
+
+impl Baseline {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Baseline> {
+        Baseline::parse(&read_to_string(path)?)
+    }
+
+    pub fn parse(s: &str) -> Result<Baseline> {
+        s.lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| {
+                let mut fields = l.split('\t');
+                let mut next = || fields.next().ok_or_else(|| anyhow!("Invalid baseline line: {}", l));
+                let day = next()?.parse()?;
+                let phase = next()?.parse()?;
+                let nanos = next()?.parse()?;
+                Ok(((day, phase), Duration::from_nanos(nanos)))
+            })
+            .collect::<Result<_>>()
+            .map(Baseline)
+    }
+
+    /// The medians of `results`, contended days are left out
+    pub fn from_results(results: &[DayBench]) -> Baseline {
+        Baseline(results.iter()
+            .filter(|r| !r.contended)
+            .flat_map(|r| Phase::ALL.iter().filter_map(move |&p| Some(((r.day, p), r.phase(p)?.median))))
+            .collect())
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut keys: Vec<_> = self.0.keys().collect();
+        keys.sort_by_key(|(day, phase)| (*day, *phase as usize));
+        let s: String = keys.into_iter()
+            .map(|k| format!("{}\t{}\t{}\n", k.0, k.1, self.0[k].as_nanos()))
+            .collect();
+        write(path, s)?;
+        Ok(())
+    }
+
+    pub fn get(&self, day: u32, phase: Phase) -> Option<Duration> {
+        self.0.get(&(day, phase)).copied()
+    }
+
+    /// Relative change of `current` against the baseline in percent
+    pub fn change(&self, day: u32, phase: Phase, current: Duration) -> Option<f64> {
+        self.get(day, phase)
+            .filter(|b| !b.is_zero())
+            .map(|b| (current.as_secs_f64() - b.as_secs_f64()) / b.as_secs_f64() * 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples: Vec<_> = [4, 2, 8, 6].iter().map(|&n| Duration::from_millis(n)).collect();
+        let s = Stats::from_samples(&samples);
+        assert_eq!(s.min, Duration::from_millis(2));
+        assert_eq!(s.median, Duration::from_millis(5));
+        assert_eq!(s.mean, Duration::from_millis(5));
+        assert!((s.stddev.as_secs_f64() - 5f64.sqrt() / 1000.0).abs() < 1e-9);
+
+        let s = Stats::from_samples(&samples[0..3]);
+        assert_eq!(s.median, Duration::from_millis(4));
+    }
+
+    #[test]
+    fn failing_day() {
+        let ms = |n| Some(Duration::from_millis(n));
+        let mut runs = 0;
+        let bench = bench_day(4, 3, 1, || {
+            runs += 1;
+            DayResult {
+                day: 4,
+                parse_time: ms(runs),
+                part1_time: ms(2),
+                error: Some(Failure::new(Phase::Part2, FailureKind::Panic, "boom")),
+                ..Default::default()
+            }
+        });
+        assert_eq!(runs, 4);
+        assert_eq!(bench.parse.map(|s| s.median), ms(3));
+        assert_eq!(bench.part1.map(|s| s.min), ms(2));
+        assert!(bench.part2.is_none());
+        assert_eq!(bench.error.map(|e| e.kind), Some(FailureKind::Panic));
+
+        let mut runs = 0;
+        let bench = bench_day(5, 3, 1, || {
+            runs += 1;
+            DayResult { day: 5, error: Some(Failure::new(Phase::Parse, FailureKind::Timeout, "slow")), ..Default::default() }
+        });
+        assert_eq!(runs, 1);
+        assert!(bench.parse.is_none() && Baseline::from_results(&[bench]).get(5, Phase::Parse).is_none());
+    }
+
+    #[test]
+    fn baseline() {
+        let b = Baseline::parse("1\tparse\t1000\n1\tpart1\t2000\n").unwrap();
+        assert_eq!(b.get(1, Phase::Parse), Some(Duration::from_micros(1)));
+        assert_eq!(b.get(1, Phase::Part2), None);
+        let change = b.change(1, Phase::Part1, Duration::from_micros(3)).unwrap();
+        assert!((change - 50.0).abs() < 1e-9);
+        assert!(Baseline::parse("1\tparse").is_err());
+        assert!(Baseline::parse("1\tpart3\t10").is_err());
+    }
+}
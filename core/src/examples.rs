@@ -0,0 +1,140 @@
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+
+use crate::SolutionFn;
+
+const OPEN: &str = "<pre><code>";
+const CLOSE: &str = "</code></pre>";
+
+/// The text of all `<pre><code>` blocks in a puzzle page, in page order. Markup inside a block,
+/// like the `<em>` around highlighted answers, is dropped.
+pub fn extract(html: &str) -> Vec<String> {
+    let mut examples = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find(OPEN) {
+        rest = &rest[start + OPEN.len()..];
+        let end = rest.find(CLOSE).unwrap_or(rest.len());
+        examples.push(unescape(&strip_tags(&rest[..end])));
+        rest = &rest[end..];
+    }
+    examples
+}
+
+pub(crate) fn strip_tags(s: &str) -> String {
+    let mut in_tag = false;
+    s.chars()
+        .filter(|&c| match c {
+            '<' => { in_tag = true; false },
+            '>' if in_tag => { in_tag = false; false },
+            _ => !in_tag,
+        })
+        .collect()
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Location of the `n`th example (counting from 1) of `day`
+pub fn example_path<P: AsRef<Path>>(examples_dir: P, day: u32, n: usize) -> PathBuf {
+    examples_dir.as_ref().join(format!("day_{:02}_{}.txt", day, n))
+}
+
+/// Extracts the examples from the saved puzzle page `html` into `examples_dir` as
+/// `day_NN_1.txt`, `day_NN_2.txt`, ... Existing example files are overwritten.
+pub fn save<P: AsRef<Path>, Q: AsRef<Path>>(html: P, examples_dir: Q, day: u32) -> Result<Vec<PathBuf>> {
+    let html = html.as_ref();
+    let page = read_to_string(html).with_context(|| format!("Cannot read {}", html.display()))?;
+    let examples = extract(&page);
+    if examples.is_empty() {
+        return Err(anyhow!("No examples found in {}", html.display()));
+    }
+
+    create_dir_all(&examples_dir)?;
+    examples.iter().enumerate().map(|(i, example)| {
+        let path = example_path(&examples_dir, day, i + 1);
+        write(&path, example)?;
+        Ok(path)
+    }).collect()
+}
+
+/// Solves `part` of `day` for the example in `path` and compares it with `expected`
+pub fn check<P: AsRef<Path>>(get_solution: SolutionFn, day: u32, path: P, part: u8, expected: &str) -> Result<()> {
+    let path = path.as_ref();
+    let input = read_to_string(path).with_context(|| format!("Cannot read example {}", path.display()))?;
+
+    let mut p = get_solution(day)?;
+    p.parse(&input)?;
+    let answer = match part {
+        1 => p.part1()?,
+        2 => p.part2()?,
+        _ => return Err(anyhow!("There is no part {}", part)),
+    };
+
+    if answer.matches(expected) {
+        Ok(())
+    } else {
+        Err(anyhow!("Part {} of {} gave {}, expected {}", part, path.display(), answer.to_line(), expected))
+    }
+}
+
+/// Tests a day against an extracted example. The example is read from the `examples` directory
+/// of the crate, the solution comes from `crate::days::get_solution`.
+///
+/// ```ignore
+/// example_test!(example, 3, "day_03_1.txt", part1 = 4361, part2 = 467835);
+/// ```
+#[macro_export]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! example_test {
+    ($name:ident, $day:expr, $file:expr, part1 = $p1:expr, part2 = $p2:expr) => {
+        mod $name {
+            $crate::example_test!(@part part1, 1, $day, $file, $p1);
+            $crate::example_test!(@part part2, 2, $day, $file, $p2);
+        }
+    };
+    ($name:ident, $day:expr, $file:expr, part1 = $p1:expr) => {
+        mod $name {
+            $crate::example_test!(@part part1, 1, $day, $file, $p1);
+        }
+    };
+    ($name:ident, $day:expr, $file:expr, part2 = $p2:expr) => {
+        mod $name {
+            $crate::example_test!(@part part2, 2, $day, $file, $p2);
+        }
+    };
+    (@part $fn:ident, $part:expr, $day:expr, $file:expr, $expected:expr) => {
+        #[test]
+        fn $fn() {
+            let path = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("examples").join($file);
+            if let Err(e) = $crate::examples::check(crate::days::get_solution, $day, path, $part, &$expected.to_string()) {
+                panic!("{:#}", e);
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_blocks() {
+        let html = "<p>For example:</p>\n<pre><code>1abc2\n<em>pqr3stu8vwx</em>\n</code></pre>\n\
+                    <p>Then</p><pre><code>a -&gt; b &amp;&amp; &lt;c&gt;\n</code></pre>";
+        assert_eq!(extract(html), vec!["1abc2\npqr3stu8vwx\n", "a -> b && <c>\n"]);
+        assert!(extract("<code>inline</code>").is_empty());
+    }
+
+    #[test]
+    fn path() {
+        assert_eq!(example_path("2023/examples", 3, 2), PathBuf::from("2023/examples/day_03_2.txt"));
+    }
+}
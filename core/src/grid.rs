@@ -0,0 +1,862 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Add, Index, IndexMut};
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Error, Result};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Grid<T> {
+    pub dim_x: usize,
+    pub dim_y: usize,
+    pub elements: Vec<T>
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Coord {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl From<(isize, isize)> for Coord {
+    fn from(t: (isize, isize)) -> Coord {
+        Coord { x: t.0, y: t.1 }
+    }
+}
+
+impl Coord {
+    /// Number of orthogonal steps to `other`
+    pub fn manhattan(self, other: Coord) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+/// Offsets of the orthogonal neighbours, clockwise starting up
+pub const NEIGHBOURS4: [Coord; 4] = [
+    Coord { x: 0, y: -1 },
+    Coord { x: 1, y: 0 },
+    Coord { x: 0, y: 1 },
+    Coord { x: -1, y: 0 },
+];
+
+/// Offsets of the orthogonal and diagonal neighbours, clockwise starting up
+pub const NEIGHBOURS8: [Coord; 8] = [
+    Coord { x: 0, y: -1 },
+    Coord { x: 1, y: -1 },
+    Coord { x: 1, y: 0 },
+    Coord { x: 1, y: 1 },
+    Coord { x: 0, y: 1 },
+    Coord { x: -1, y: 1 },
+    Coord { x: -1, y: 0 },
+    Coord { x: -1, y: -1 },
+];
+
+/// A cell next to, or in sight of, another cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Neighbour<'a, T> {
+    pub coord: Coord,
+    pub idx: usize,
+    pub value: &'a T,
+}
+
+impl Add for Coord {
+    type Output = Coord;
+    fn add(mut self, rhs: Coord) -> Coord {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new<I: IntoIterator<Item=T>>(v: I, dim_x: usize, dim_y: usize) -> Grid<T> {
+        let elements = v.into_iter().collect();
+        Grid { dim_x, dim_y, elements }
+    }
+
+    /// Builds a grid from lines of text, `cell` converts every character. All lines must have the
+    /// same length, trailing empty lines are ignored.
+    pub fn from_str_with<F>(s: &str, mut cell: F) -> Result<Grid<T>>
+    where
+        F: FnMut(char) -> Result<T>,
+    {
+        let lines: Vec<&str> = s.trim_end_matches(['\r', '\n']).lines().collect();
+        let dim_x = lines.first().map_or(0, |l| l.chars().count());
+        if dim_x == 0 {
+            return Err(anyhow!("Empty grid"));
+        }
+
+        let mut elements = Vec::with_capacity(dim_x * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let len = line.chars().count();
+            if len != dim_x {
+                return Err(anyhow!("Line {} has {} cells, expected {} like the first line", y + 1, len, dim_x));
+            }
+            for (x, c) in line.chars().enumerate() {
+                elements.push(cell(c).with_context(|| format!("Invalid cell {:?} at line {}, column {}", c, y + 1, x + 1))?);
+            }
+        }
+        Ok(Grid { dim_x, dim_y: lines.len(), elements })
+    }
+
+    /// Like [`Grid::from_str_with`], and records where each of the `markers` is. The markers are
+    /// converted by `cell` like any other character.
+    pub fn from_str_with_markers<F>(s: &str, markers: &[char], mut cell: F) -> Result<(Grid<T>, Markers)>
+    where
+        F: FnMut(char) -> Result<T>,
+    {
+        let mut found: Vec<(char, usize)> = Vec::new();
+        let mut idx = 0;
+        let grid = Grid::from_str_with(s, |c| {
+            if markers.contains(&c) {
+                found.push((c, idx));
+            }
+            idx += 1;
+            cell(c)
+        })?;
+
+        let mut positions: HashMap<char, Vec<Coord>> = markers.iter().map(|&m| (m, Vec::new())).collect();
+        for (c, idx) in found {
+            positions.get_mut(&c).unwrap().push(grid.idx_to_coord(idx));
+        }
+        Ok((grid, Markers { positions }))
+    }
+
+    pub fn walk_fixed<C: Into<Coord>>(&self, from: C, dx: isize, dy: isize, wrap_x: bool, wrap_y: bool) -> Walker {
+        Walker {position: from.into(), dx, dy, max_x:self.dim_x, max_y: self.dim_y, wrap_x, wrap_y }
+    }
+
+    pub fn idx_to_coord(&self, idx: usize) -> Coord {
+        let x = (idx % self.dim_x) as isize; 
+        let y = (idx / self.dim_x) as isize;
+        Coord { x , y }
+    }
+
+    pub fn coord_to_idx(&self, c: Coord) -> usize {
+        let pos = c.x + c.y * self.dim_x as isize;
+        usize::try_from(pos).expect("Cannot index negative coords")
+    }
+
+    pub fn in_bounds(&self, c: Coord) -> bool {
+        c.x >= 0 && c.x < self.dim_x as isize && c.y >= 0 && c.y < self.dim_y as isize
+    }
+
+    pub fn get(&self, c: Coord) -> Option<&T> {
+        if self.in_bounds(c) {
+            Some(&self.elements[self.coord_to_idx(c)])
+        } else {
+            None
+        }
+    }
+
+    fn neighbour(&self, coord: Coord) -> Neighbour<'_, T> {
+        let idx = self.coord_to_idx(coord);
+        Neighbour { coord, idx, value: &self.elements[idx] }
+    }
+
+    /// The cells at `offsets` from `c` that are inside the grid
+    pub fn neighbours<'a, O>(&'a self, c: Coord, offsets: O) -> impl Iterator<Item=Neighbour<'a, T>>
+    where
+        O: IntoIterator<Item=Coord>,
+        O::IntoIter: 'a,
+    {
+        offsets.into_iter()
+            .map(move |o| c + o)
+            .filter(|&n| self.in_bounds(n))
+            .map(|n| self.neighbour(n))
+    }
+
+    /// The orthogonal neighbours of `c`, see [`NEIGHBOURS4`]
+    pub fn neighbours4(&self, c: Coord) -> impl Iterator<Item=Neighbour<'_, T>> {
+        self.neighbours(c, NEIGHBOURS4)
+    }
+
+    /// The orthogonal and diagonal neighbours of `c`, see [`NEIGHBOURS8`]
+    pub fn neighbours8(&self, c: Coord) -> impl Iterator<Item=Neighbour<'_, T>> {
+        self.neighbours(c, NEIGHBOURS8)
+    }
+
+    /// Walks from `from` in steps of `direction` and stops at the first cell matching `visible`,
+    /// `None` when the walk leaves the grid first
+    pub fn line_of_sight<F>(&self, from: Coord, direction: Coord, mut visible: F) -> Option<Neighbour<'_, T>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        assert!(direction != Coord::default(), "Cannot look without a direction");
+        self.walk_fixed(from, direction.x, direction.y, false, false)
+            .find(|&c| visible(&self[c]))
+            .map(|c| self.neighbour(c))
+    }
+
+    /// The first cells matching `visible` looking from `from` in each of `directions`
+    pub fn in_sight<'a, O, F>(&'a self, from: Coord, directions: O, mut visible: F) -> impl Iterator<Item=Neighbour<'a, T>>
+    where
+        O: IntoIterator<Item=Coord>,
+        O::IntoIter: 'a,
+        F: FnMut(&T) -> bool + 'a,
+    {
+        directions.into_iter().filter_map(move |d| self.line_of_sight(from, d, &mut visible))
+    }
+
+    pub fn iter_row(&self, y: usize) -> impl Iterator<Item=&T> {
+        let start = y * self.dim_x;
+        let end = start + self.dim_x;
+        self.elements[start..end].iter()
+    }
+
+    pub fn iter_col(&self, x: usize) -> impl Iterator<Item=&T> {
+        self.elements.iter().skip(x).step_by(self.dim_x)
+    }
+
+    /// The `dim_x` by `dim_y` rectangle starting at `origin`, which has to fit in the grid
+    pub fn view(&self, origin: Coord, dim_x: usize, dim_y: usize) -> View<'_, T> {
+        let end = origin + Coord { x: dim_x as isize, y: dim_y as isize };
+        assert!(self.in_bounds(origin) && end.x <= self.dim_x as isize && end.y <= self.dim_y as isize,
+            "View of {}x{} at {:?} does not fit a {}x{} grid", dim_x, dim_y, origin, self.dim_x, self.dim_y);
+        View { grid: self, origin, dim_x, dim_y }
+    }
+
+}
+
+impl<T: Clone> Grid<T> {
+    /// A new grid of `dim_x` by `dim_y` where every cell is copied from the cell `source` gives
+    fn remap<F>(&self, dim_x: usize, dim_y: usize, source: F) -> Grid<T>
+    where
+        F: Fn(usize, usize) -> (usize, usize),
+    {
+        let elements = (0..dim_y)
+            .flat_map(|y| (0..dim_x).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let (sx, sy) = source(x, y);
+                self.elements[sx + sy * self.dim_x].clone()
+            })
+            .collect();
+        Grid { dim_x, dim_y, elements }
+    }
+
+    /// Rows become columns
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.dim_y, self.dim_x, |x, y| (y, x))
+    }
+
+    /// Turns the grid clockwise, the left column becomes the top row
+    pub fn rotate90(&self) -> Grid<T> {
+        self.remap(self.dim_y, self.dim_x, |x, y| (y, self.dim_y - 1 - x))
+    }
+
+    pub fn rotate180(&self) -> Grid<T> {
+        self.remap(self.dim_x, self.dim_y, |x, y| (self.dim_x - 1 - x, self.dim_y - 1 - y))
+    }
+
+    /// Turns the grid counterclockwise, the top row becomes the left column
+    pub fn rotate270(&self) -> Grid<T> {
+        self.remap(self.dim_y, self.dim_x, |x, y| (self.dim_x - 1 - y, x))
+    }
+
+    /// Mirrors left and right
+    pub fn flip_x(&self) -> Grid<T> {
+        self.remap(self.dim_x, self.dim_y, |x, y| (self.dim_x - 1 - x, y))
+    }
+
+    /// Mirrors top and bottom
+    pub fn flip_y(&self) -> Grid<T> {
+        self.remap(self.dim_x, self.dim_y, |x, y| (x, self.dim_y - 1 - y))
+    }
+
+    /// All 8 ways to rotate and flip the grid: the four rotations, then the four rotations of
+    /// the grid flipped left to right. The first is an unchanged copy.
+    pub fn orientations(&self) -> impl Iterator<Item=Grid<T>> + '_ {
+        (0..8).map(move |i| {
+            let g = if i < 4 { self.clone() } else { self.flip_x() };
+            match i % 4 {
+                0 => g,
+                1 => g.rotate90(),
+                2 => g.rotate180(),
+                _ => g.rotate270(),
+            }
+        })
+    }
+}
+
+/// A borrowed rectangle of a grid, indexed with coordinates relative to its top left cell
+#[derive(Debug, Clone, Copy)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Coord,
+    pub dim_x: usize,
+    pub dim_y: usize,
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn in_bounds(&self, c: Coord) -> bool {
+        c.x >= 0 && c.x < self.dim_x as isize && c.y >= 0 && c.y < self.dim_y as isize
+    }
+
+    pub fn get(&self, c: Coord) -> Option<&'a T> {
+        if self.in_bounds(c) {
+            self.grid.get(self.origin + c)
+        } else {
+            None
+        }
+    }
+
+    /// Position of `c` in the underlying grid
+    pub fn to_grid_coord(&self, c: Coord) -> Coord {
+        self.origin + c
+    }
+
+    pub fn iter_row(&self, y: usize) -> impl Iterator<Item=&'a T> {
+        let start = self.grid.coord_to_idx(self.origin + Coord { x: 0, y: y as isize });
+        self.grid.elements[start..start + self.dim_x].iter()
+    }
+
+    /// All cells in reading order
+    pub fn iter(&self) -> impl Iterator<Item=&'a T> + '_ {
+        (0..self.dim_y).flat_map(move |y| self.iter_row(y))
+    }
+}
+
+impl<T: Clone> View<'_, T> {
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid::new(self.iter().cloned(), self.dim_x, self.dim_y)
+    }
+}
+
+impl<T> Index<Coord> for View<'_, T> {
+    type Output = T;
+
+    fn index(&self, c: Coord) -> &Self::Output {
+        self.get(c).unwrap_or_else(|| panic!("{:?} is outside the {}x{} view", c, self.dim_x, self.dim_y))
+    }
+}
+
+/// An unbounded grid that only stores the cells that were set, every other cell has the
+/// `default` value. The bounding box grows to cover each cell that is set, and is not shrunk when
+/// cells are removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Coord, T>,
+    default: T,
+    bounds: Option<(Coord, Coord)>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> SparseGrid<T> {
+        SparseGrid { cells: HashMap::new(), default, bounds: None }
+    }
+
+    /// The top left and bottom right corners of the bounding box, `None` before any cell is set
+    pub fn bounds(&self) -> Option<(Coord, Coord)> {
+        self.bounds
+    }
+
+    pub fn dim_x(&self) -> usize {
+        self.bounds.map_or(0, |(min, max)| (max.x - min.x) as usize + 1)
+    }
+
+    pub fn dim_y(&self) -> usize {
+        self.bounds.map_or(0, |(min, max)| (max.y - min.y) as usize + 1)
+    }
+
+    pub fn in_bounds(&self, c: Coord) -> bool {
+        self.bounds.is_some_and(|(min, max)| c.x >= min.x && c.x <= max.x && c.y >= min.y && c.y <= max.y)
+    }
+
+    fn extend_bounds(&mut self, c: Coord) {
+        self.bounds = Some(match self.bounds {
+            None => (c, c),
+            Some((min, max)) => (
+                Coord { x: min.x.min(c.x), y: min.y.min(c.y) },
+                Coord { x: max.x.max(c.x), y: max.y.max(c.y) },
+            ),
+        });
+    }
+
+    /// The value of `c`, the default when it was never set
+    pub fn get(&self, c: Coord) -> &T {
+        self.cells.get(&c).unwrap_or(&self.default)
+    }
+
+    /// Whether `c` was set, even to the default value
+    pub fn contains(&self, c: Coord) -> bool {
+        self.cells.contains_key(&c)
+    }
+
+    /// Sets `c` and returns its previous value if it was set
+    pub fn insert(&mut self, c: Coord, value: T) -> Option<T> {
+        self.extend_bounds(c);
+        self.cells.insert(c, value)
+    }
+
+    pub fn remove(&mut self, c: Coord) -> Option<T> {
+        self.cells.remove(&c)
+    }
+
+    /// Number of cells that were set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The cells that were set, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item=(Coord, &T)> {
+        self.cells.iter().map(|(&c, v)| (c, v))
+    }
+
+    /// The values of the `offsets` around `c`, unset cells included
+    pub fn neighbours<'a, I>(&'a self, c: Coord, offsets: I) -> impl Iterator<Item=(Coord, &'a T)> + 'a
+    where
+        I: IntoIterator<Item=Coord>,
+        I::IntoIter: 'a,
+    {
+        offsets.into_iter().map(move |o| (c + o, self.get(c + o)))
+    }
+
+    pub fn neighbours4(&self, c: Coord) -> impl Iterator<Item=(Coord, &T)> {
+        self.neighbours(c, NEIGHBOURS4)
+    }
+
+    pub fn neighbours8(&self, c: Coord) -> impl Iterator<Item=(Coord, &T)> {
+        self.neighbours(c, NEIGHBOURS8)
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// The value of `c` to modify, it is set to the default first when needed
+    pub fn entry(&mut self, c: Coord) -> &mut T {
+        self.extend_bounds(c);
+        self.cells.entry(c).or_insert_with(|| self.default.clone())
+    }
+
+    /// A dense copy of the bounding box and the position of its top left cell. It is empty when
+    /// no cell was set.
+    pub fn to_grid(&self) -> (Grid<T>, Coord) {
+        let Some((min, max)) = self.bounds else {
+            return (Grid::new(Vec::new(), 0, 0), Coord::default());
+        };
+        let elements = (min.y..=max.y).flat_map(|y| (min.x..=max.x).map(move |x| Coord { x, y }));
+        (Grid::new(elements.map(|c| self.get(c).clone()), self.dim_x(), self.dim_y()), min)
+    }
+}
+
+impl<T: Clone + PartialEq> SparseGrid<T> {
+    /// Stores the cells of `grid` that differ from `default`, the bounding box covers all of it
+    pub fn from_grid(grid: &Grid<T>, default: T) -> SparseGrid<T> {
+        let mut sparse = SparseGrid::new(default);
+        if !grid.elements.is_empty() {
+            sparse.extend_bounds(Coord::default());
+            sparse.extend_bounds(grid.idx_to_coord(grid.elements.len() - 1));
+        }
+        for (idx, value) in grid.elements.iter().enumerate() {
+            if *value != sparse.default {
+                sparse.cells.insert(grid.idx_to_coord(idx), value.clone());
+            }
+        }
+        sparse
+    }
+}
+
+impl<T> Index<Coord> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, c: Coord) -> &Self::Output {
+        self.get(c)
+    }
+}
+
+impl<T: Clone> IndexMut<Coord> for SparseGrid<T> {
+    fn index_mut(&mut self, c: Coord) -> &mut Self::Output {
+        self.entry(c)
+    }
+}
+
+impl<T> fmt::Display for SparseGrid<T> where T: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        if let Some((min, max)) = self.bounds {
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    write!(f, "{} ", self.get(Coord { x, y }))?;
+                }
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Positions of marker characters found by [`Grid::from_str_with_markers`], in reading order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Markers {
+    positions: HashMap<char, Vec<Coord>>,
+}
+
+impl Markers {
+    /// Every position of `marker`
+    pub fn all(&self, marker: char) -> &[Coord] {
+        self.positions.get(&marker).map_or(&[], |p| p.as_slice())
+    }
+
+    /// The position of a marker that should appear exactly once
+    pub fn find(&self, marker: char) -> Result<Coord> {
+        match self.all(marker) {
+            [c] => Ok(*c),
+            [] => Err(anyhow!("No {:?} in the grid", marker)),
+            p => Err(anyhow!("{:?} is in the grid {} times", marker, p.len())),
+        }
+    }
+}
+
+/// One byte per cell, only ascii is accepted
+impl FromStr for Grid<u8> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Grid<u8>> {
+        Grid::from_str_with(s, |c| u8::try_from(c).ok().filter(u8::is_ascii).ok_or_else(|| anyhow!("Not ascii")))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Grid<char>> {
+        Grid::from_str_with(s, Ok)
+    }
+}
+
+impl<T> Grid<T> where T: Eq {
+    pub fn compare_rows(&self, row1: usize, row2: usize) -> bool {
+        self.iter_row(row1).zip(self.iter_row(row2)).all(|(a, b)| a == b)
+    }
+
+    pub fn compare_cols(&self, col1: usize, col2: usize) -> bool {
+        self.iter_col(col1).zip(self.iter_col(col2)).all(|(a, b)| a == b)
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    //FIXME needs better oob check
+    fn index(&self, c: Coord) -> &Self::Output {
+        let pos = c.x + c.y * self.dim_x as isize;
+        &self.elements[usize::try_from(pos).expect("Cannot index negative coords")]
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    //FIXME needs better oob check
+    fn index_mut(&mut self, c: Coord) -> &mut Self::Output {
+        let pos = c.x + c.y * self.dim_x as isize;
+        &mut self.elements[usize::try_from(pos).expect("Cannot index negative coords")]
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    //FIXME needs better oob check
+    fn index(&self, p: usize) -> &Self::Output {
+        &self.elements[p]
+    }
+}
+
+impl<T> Grid<T> {
+    fn write_cells<F>(&self, f: &mut fmt::Formatter, cell: F) -> fmt::Result
+    where
+        F: Fn(&mut fmt::Formatter, &T) -> fmt::Result,
+    {
+        for p in (0..self.elements.len()).step_by(self.dim_x) {
+            for e in &self.elements[p..p + self.dim_x] {
+                cell(f, e)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> fmt::Display for Grid<T> where T: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        self.write_cells(f, |f, e| write!(f, "{} ", e))
+    }
+}
+
+/// Displays a grid of bytes as characters, see [`Grid::ascii`]
+pub struct Ascii<'a>(&'a Grid<u8>);
+
+impl Grid<u8> {
+    /// The grid with its cells shown as characters, its `Display` shows them as numbers
+    pub fn ascii(&self) -> Ascii<'_> {
+        Ascii(self)
+    }
+}
+
+impl fmt::Display for Ascii<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
+        self.0.write_cells(f, |f, &e| write!(f, "{} ", char::from(e)))
+    }
+}
+
+pub struct Walker {
+    position: Coord,
+    dx: isize,
+    dy: isize,
+    max_x: usize,
+    max_y: usize,
+    wrap_x: bool,
+    wrap_y: bool
+}
+
+fn wrap_or_oob(x:isize, max: isize, wrap: bool) -> Option<isize> {
+    if x < 0 {
+        if wrap {
+            Some(x.rem_euclid(max))
+        } else {
+            None
+        }
+    } else if x >= max {
+        if wrap {
+            Some(x % max)
+        } else {
+            None
+        }
+    } else {
+        Some(x)
+    }
+}
+
+impl Iterator for Walker {
+    type Item = Coord;
+    fn next(&mut self) -> Option<Self::Item> {
+        let new_x = wrap_or_oob(self.position.x + self.dx, self.max_x as isize, self.wrap_x)?;
+        let new_y = wrap_or_oob(self.position.y + self.dy, self.max_y as isize, self.wrap_y)?;
+        self.position = (new_x, new_y).into();
+        Some(Coord::from((new_x, new_y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coords() {
+        let grid = Grid::new(vec![1,1,1,1,1,2,2,2,2,2,3,3,3,3,3], 5, 3);
+        assert_eq!(grid.idx_to_coord(0), (0,0).into());
+        assert_eq!(grid.idx_to_coord(1), (1,0).into());
+        assert_eq!(grid.idx_to_coord(5), (0,1).into());
+        assert_eq!(grid.idx_to_coord(24), (4,4).into());
+    }
+
+    #[test]
+    fn iter_row_col() {
+        let grid = Grid::new(vec![1,2,3,4,5,6,7,8,9], 3, 3);
+        assert_eq!(grid.iter_row(0).collect::<Vec<_>>(), vec![&1,&2,&3]);
+        assert_eq!(grid.iter_row(1).collect::<Vec<_>>(), vec![&4,&5,&6]);
+        assert_eq!(grid.iter_row(2).collect::<Vec<_>>(), vec![&7,&8,&9]);
+        assert_eq!(grid.iter_col(0).collect::<Vec<_>>(), vec![&1,&4,&7]);
+        assert_eq!(grid.iter_col(1).collect::<Vec<_>>(), vec![&2,&5,&8]);
+        assert_eq!(grid.iter_col(2).collect::<Vec<_>>(), vec![&3,&6,&9]);
+    }
+
+    #[test]
+    fn compare() {
+        let grid = Grid::new(vec![1,1,1,2,2,2,1,1,1], 3, 3);
+        assert!(grid.compare_rows(0, 2));
+        assert!(!grid.compare_rows(0, 1));
+        assert!(grid.compare_cols(0, 1));
+    }
+
+    #[test]
+    fn from_str() {
+        let grid: Grid<u8> = "#.#\r\n..#\r\n\r\n".parse().unwrap();
+        assert_eq!(grid, Grid::new(b"#.#..#".iter().copied(), 3, 2));
+        let grid: Grid<char> = "ab\ncd".parse().unwrap();
+        assert_eq!(grid[Coord::from((1, 1))], 'd');
+
+        let digits = Grid::from_str_with("123\n456\n", |c| c.to_digit(10).ok_or_else(|| anyhow!("Not a digit"))).unwrap();
+        assert_eq!(digits.elements.iter().sum::<u32>(), 21);
+
+        let e = "...\n..\n...".parse::<Grid<u8>>().unwrap_err();
+        assert_eq!(e.to_string(), "Line 2 has 2 cells, expected 3 like the first line");
+        let e = Grid::from_str_with("12\n3x", |c| c.to_digit(10).ok_or_else(|| anyhow!("Not a digit"))).unwrap_err();
+        assert_eq!(format!("{:#}", e), "Invalid cell 'x' at line 2, column 2: Not a digit");
+        assert!("".parse::<Grid<char>>().is_err());
+        assert!("ab\n\ncd".parse::<Grid<char>>().is_err());
+
+        let bytes = "#.\nL#".parse::<Grid<u8>>().unwrap();
+        assert_eq!(bytes.ascii().to_string(), "# . \nL # \n");
+        assert_eq!(bytes.to_string(), "35 46 \n76 35 \n");
+    }
+
+    #[test]
+    fn markers() {
+        let (grid, markers) = Grid::from_str_with_markers("S.#\n.#E\n..#", &['S', 'E', 'X', '#'], |c| Ok(c != '#')).unwrap();
+        assert_eq!(markers.find('S').unwrap(), (0, 0).into());
+        assert_eq!(markers.find('E').unwrap(), (2, 1).into());
+        assert!(grid[markers.find('E').unwrap()]);
+        assert_eq!(markers.all('#'), [(2, 0).into(), (1, 1).into(), (2, 2).into()]);
+        assert!(markers.find('X').is_err());
+        assert_eq!(markers.find('#').unwrap_err().to_string(), "'#' is in the grid 3 times");
+    }
+
+    #[test]
+    fn transforms() {
+        // 1 2 3
+        // 4 5 6
+        let grid = Grid::new(1..=6, 3, 2);
+        assert_eq!(grid.transpose(), Grid::new([1, 4, 2, 5, 3, 6], 2, 3));
+        assert_eq!(grid.rotate90(), Grid::new([4, 1, 5, 2, 6, 3], 2, 3));
+        assert_eq!(grid.rotate180(), Grid::new([6, 5, 4, 3, 2, 1], 3, 2));
+        assert_eq!(grid.rotate270(), Grid::new([3, 6, 2, 5, 1, 4], 2, 3));
+        assert_eq!(grid.flip_x(), Grid::new([3, 2, 1, 6, 5, 4], 3, 2));
+        assert_eq!(grid.flip_y(), Grid::new([4, 5, 6, 1, 2, 3], 3, 2));
+        assert_eq!(grid.rotate90().rotate270(), grid);
+        assert_eq!(grid.flip_x().rotate180(), grid.flip_y());
+
+        let orientations: Vec<_> = grid.orientations().collect();
+        assert_eq!(orientations.len(), 8);
+        assert_eq!(orientations[0], grid);
+        assert!(orientations.contains(&grid.transpose()));
+        assert!(orientations.iter().enumerate().all(|(i, g)| !orientations[i + 1..].contains(g)));
+    }
+
+    #[test]
+    fn views() {
+        let grid = Grid::new(0..20, 5, 4);
+        let view = grid.view((1, 2).into(), 3, 2);
+        assert_eq!(view[Coord::from((0, 0))], 11);
+        assert_eq!(view.get((2, 1).into()), Some(&18));
+        assert_eq!(view.get((3, 0).into()), None);
+        assert_eq!(view.to_grid_coord((2, 1).into()), (3, 3).into());
+        assert_eq!(view.iter().copied().collect::<Vec<_>>(), [11, 12, 13, 16, 17, 18]);
+        assert_eq!(view.to_grid().rotate90().view((0, 0).into(), 2, 1).to_grid(), Grid::new([16, 11], 2, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit")]
+    fn view_outside() {
+        Grid::new(0..20, 5, 4).view((3, 0).into(), 3, 1);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(vec![1,2,3,4,5,6,7,8,9,10,11,12], 4, 3);
+        let values = |it: &mut dyn Iterator<Item=Neighbour<i32>>| it.map(|n| *n.value).collect::<Vec<_>>();
+        assert_eq!(values(&mut grid.neighbours4((1, 1).into())), [2, 7, 10, 5]);
+        assert_eq!(values(&mut grid.neighbours8((1, 1).into())), [2, 3, 7, 11, 10, 9, 5, 1]);
+        assert_eq!(values(&mut grid.neighbours8((0, 0).into())), [2, 6, 5]);
+        assert_eq!(values(&mut grid.neighbours4((3, 2).into())), [8, 11]);
+
+        let knight = grid.neighbours((0, 0).into(), [(1, 2).into(), (2, 1).into(), (-1, 2).into()]).collect::<Vec<_>>();
+        assert_eq!(knight, [Neighbour { coord: (1, 2).into(), idx: 9, value: &10 }, Neighbour { coord: (2, 1).into(), idx: 6, value: &7 }]);
+        assert_eq!(grid.get((4, 0).into()), None);
+        assert_eq!(grid.get((3, 2).into()), Some(&12));
+    }
+
+    #[test]
+    fn sight() {
+        let grid = Grid::new(b"#...#.L.#..L".to_vec(), 4, 3);
+        let seat = |&c: &u8| c != b'.';
+        assert_eq!(grid.line_of_sight((0, 0).into(), (1, 0).into(), seat), None);
+        assert_eq!(grid.line_of_sight((0, 0).into(), (1, 1).into(), seat), None);
+        assert_eq!(grid.line_of_sight((0, 0).into(), (0, 1).into(), seat).map(|n| n.idx), Some(4));
+        let seen = grid.in_sight((3, 0).into(), NEIGHBOURS8, seat).map(|n| (n.coord, *n.value)).collect::<Vec<_>>();
+        assert_eq!(seen, [((3, 2).into(), b'L'), ((2, 1).into(), b'L'), ((0, 0).into(), b'#')]);
+    }
+
+    #[test]
+    fn sparse() {
+        let mut grid = SparseGrid::new('.');
+        assert_eq!((grid.bounds(), grid.dim_x(), grid.to_string()), (None, 0, String::new()));
+        grid.insert((-2, 1).into(), '#');
+        grid[(1, -1).into()] = 'O';
+        assert_eq!(grid.bounds(), Some(((-2, -1).into(), (1, 1).into())));
+        assert_eq!((grid.dim_x(), grid.dim_y(), grid.len()), (4, 3, 2));
+        assert_eq!((grid[(0, 0).into()], grid[(5, 5).into()], grid[(-2, 1).into()]), ('.', '.', '#'));
+        assert_eq!(grid.to_string(), ". . . O \n. . . . \n# . . . \n");
+        assert_eq!(grid.neighbours4((1, 0).into()).map(|(_, &v)| v).collect::<String>(), "O...");
+
+        // removing a cell keeps the bounding box
+        assert_eq!(grid.remove((1, -1).into()), Some('O'));
+        assert!(!grid.contains((1, -1).into()) && grid.in_bounds((1, -1).into()));
+        assert_eq!(grid.dim_x(), 4);
+    }
+
+    #[test]
+    fn sparse_dense() {
+        let dense = Grid::new("..#.#...".chars(), 4, 2);
+        let sparse = SparseGrid::from_grid(&dense, '.');
+        assert_eq!((sparse.len(), sparse.dim_x(), sparse.dim_y()), (2, 4, 2));
+        assert_eq!(sparse.to_grid(), (dense, (0, 0).into()));
+
+        let mut sparse = SparseGrid::new(0);
+        sparse.insert((-1, 3).into(), 1);
+        sparse.insert((1, 4).into(), 2);
+        assert_eq!(sparse.to_grid(), (Grid::new([1, 0, 0, 0, 0, 2], 3, 2), (-1, 3).into()));
+        assert!(SparseGrid::new(0).to_grid().0.elements.is_empty());
+    }
+
+    #[test]
+    fn walk_nowrap() {
+        let grid = Grid::new(vec![1,1,1,1,1,2,2,2,2,2,3,3,3,3,3], 5, 3);
+        let start = (0,0);
+        let mut walker = grid.walk_fixed(start, 2, 1, false, false);
+        assert_eq!(walker.next(), Some((2,1).into()));
+        assert_eq!(walker.next(), Some((4,2).into()));
+        assert_eq!(walker.next(), None);
+    }
+    #[test]
+    fn walk_diagonal() {
+        let grid = Grid::new(vec![1,1,1,1,1,2,2,2,2,2,3,3,3,3,3,4,4,4,4,4,5,5,5,5,5], 5, 5);
+        let start = (0,0);
+        let mut walker = grid.walk_fixed(start, 1, 1, false, false);
+        assert_eq!(walker.next(), Some((1,1).into()));
+        assert_eq!(walker.next(), Some((2,2).into()));
+        assert_eq!(walker.next(), Some((3,3).into()));
+        assert_eq!(walker.next(), Some((4,4).into()));
+        assert_eq!(walker.next(), None);
+    }
+
+    #[test]
+    fn walk_diagonal_wrap() {
+        let grid = Grid::new(vec![1,1,1,1,1,2,2,2,2,2,3,3,3,3,3,4,4,4,4,4,5,5,5,5,5], 5, 5);
+        let start = (0,0);
+        let mut walker = grid.walk_fixed(start, 1, 1, true, true);
+        assert_eq!(walker.next(), Some((1,1).into()));
+        assert_eq!(walker.next(), Some((2,2).into()));
+        assert_eq!(walker.next(), Some((3,3).into()));
+        assert_eq!(walker.next(), Some((4,4).into()));
+        assert_eq!(walker.next(), Some((0,0).into()));
+    }
+
+
+    #[test]
+    fn walk_wrap_x() {
+        let grid = Grid::new(vec![1,1,1,1,1,2,2,2,2,2,3,3,3,3,3], 5, 3);
+        let start = (0,0);
+        let mut walker = grid.walk_fixed(start, 4, 1, true, false);
+        assert_eq!(walker.next(), Some((4,1).into()));
+        assert_eq!(walker.next(), Some((3,2).into()));
+        assert_eq!(walker.next(), None);
+
+        //big step
+        let mut walker = grid.walk_fixed(start, 8, 1, true, false);
+        assert_eq!(walker.next(), Some((3,1).into()));
+
+    }
+
+    #[test]
+    fn walk_wrap_x_y() {
+        let grid = Grid::new(vec![1,1,1,1,1,2,2,2,2,2,3,3,3,3,3], 5, 3);
+        let start = (0,0);
+        let mut walker = grid.walk_fixed(start, 5, 1, true, false);
+        assert_eq!(walker.next(), Some((0,1).into()));
+        assert_eq!(walker.next(), Some((0,2).into()));
+        assert_eq!(walker.next(), None);
+    }
+}
@@ -0,0 +1,268 @@
+use std::collections::BTreeMap;
+use std::fs::{read_to_string, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tabular::{Table, row};
+
+use crate::alloc;
+use crate::report::DayResult;
+
+const RELEASE: bool = !cfg!(debug_assertions);
+
+/// The timings of a day in a single run. The history of a year is a file with an entry per line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub commit: String,
+    /// The working tree had uncommitted changes
+    pub dirty: bool,
+    pub date: DateTime<Utc>,
+    /// Built with optimizations, debug timings are not comparable with release timings
+    pub release: bool,
+    /// The days ran in parallel, competing for the cores
+    #[serde(default)]
+    pub parallel: bool,
+    /// Built with the `alloc-stats` feature, which slows down every allocation
+    #[serde(default)]
+    pub alloc_stats: bool,
+    /// Measured while a day that timed out was still running
+    #[serde(default)]
+    pub contended: bool,
+    pub day: u32,
+    pub parse_ns: Option<u64>,
+    pub part1_ns: Option<u64>,
+    pub part2_ns: Option<u64>,
+    pub failed: bool,
+}
+
+impl Entry {
+    /// Time of a complete and uncontended run, without it the entry cannot be compared
+    pub fn total(&self) -> Option<Duration> {
+        match (self.failed || self.contended, self.parse_ns, self.part1_ns, self.part2_ns) {
+            (false, Some(p), Some(p1), Some(p2)) => Some(Duration::from_nanos(p + p1 + p2)),
+            _ => None,
+        }
+    }
+
+    /// Only runs made the same way have comparable timings
+    fn setup(&self) -> (bool, bool, bool) {
+        (self.release, self.parallel, self.alloc_stats)
+    }
+
+    fn revision(&self) -> String {
+        format!("{}{}", self.commit, if self.dirty { "+" } else { "" })
+    }
+}
+
+/// The abbreviated hash of HEAD and whether there are uncommitted changes
+fn git_revision() -> (String, bool) {
+    let git = |args: &[&str]| Command::new("git").args(args).output().ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string());
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(commit) => (commit, git(&["status", "--porcelain", "--untracked-files=no"]).is_some_and(|s| !s.is_empty())),
+        None => ("unknown".to_string(), false),
+    }
+}
+
+/// Appends the results of a run to the history in `path`, `parallel` tells whether the days ran
+/// in parallel
+pub fn record<P: AsRef<Path>>(path: P, results: &[DayResult], parallel: bool) -> Result<()> {
+    let (commit, dirty) = git_revision();
+    let date = Utc::now();
+    let nanos = |d: Option<Duration>| d.map(|d| d.as_nanos() as u64);
+
+    let mut f = OpenOptions::new().create(true).append(true).open(path)?;
+    for r in results {
+        let entry = Entry {
+            commit: commit.clone(),
+            dirty,
+            date,
+            release: RELEASE,
+            parallel,
+            alloc_stats: alloc::ENABLED,
+            contended: r.contended,
+            day: r.day,
+            parse_ns: nanos(r.parse_time),
+            part1_ns: nanos(r.part1_time),
+            part2_ns: nanos(r.part2_time),
+            failed: r.error.is_some(),
+        };
+        writeln!(f, "{}", serde_json::to_string(&entry)?)?;
+    }
+    Ok(())
+}
+
+/// Reads the history in `path`, a missing file is an empty history
+pub fn load<P: AsRef<Path>>(path: P) -> Result<Vec<Entry>> {
+    let path = path.as_ref();
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    read_to_string(path)?.lines()
+        .filter(|l| !l.trim().is_empty())
+        .enumerate()
+        .map(|(i, l)| serde_json::from_str(l).map_err(|e| anyhow!("{}:{}: {}", path.display(), i + 1, e)))
+        .collect()
+}
+
+/// The recorded timings of a day
+#[derive(Debug)]
+pub struct Trend<'a> {
+    pub day: u32,
+    /// Number of comparable runs
+    pub runs: usize,
+    pub best: &'a Entry,
+    /// The most recent runs, oldest first
+    pub recent: Vec<&'a Entry>,
+}
+
+impl Trend<'_> {
+    pub fn latest(&self) -> &Entry {
+        self.recent.last().unwrap()
+    }
+
+    /// Relative change of the latest run compared to the best run, in percent. There is none
+    /// when a run has no total or the best run took no measurable time.
+    pub fn change(&self) -> Option<f64> {
+        let best = self.best.total()?.as_secs_f64();
+        let latest = self.latest().total()?.as_secs_f64();
+        (best > 0.0).then(|| 100.0 * (latest - best) / best)
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|c| c > threshold)
+    }
+}
+
+/// The trend of every day with complete runs of the same build profile as this binary. The runs
+/// of a day are only compared with runs made the same way as its latest run, in parallel or not
+/// and with or without counting allocations. Only the last `recent` runs are kept. Days without
+/// a [`Trend::change`] are left out.
+pub fn trends(entries: &[Entry], recent: usize) -> Vec<Trend<'_>> {
+    let mut days: BTreeMap<u32, Vec<&Entry>> = BTreeMap::new();
+    for e in entries.iter().filter(|e| e.release == RELEASE && e.total().is_some()) {
+        days.entry(e.day).or_default().push(e);
+    }
+
+    days.into_iter()
+        .map(|(day, runs)| {
+            let setup = runs.last().unwrap().setup();
+            let runs: Vec<&Entry> = runs.into_iter().filter(|e| e.setup() == setup).collect();
+            let best = runs.iter().min_by_key(|e| e.total()).unwrap();
+            Trend { day, runs: runs.len(), best, recent: runs[runs.len().saturating_sub(recent.max(1))..].to_vec() }
+        })
+        .filter(|t| t.change().is_some())
+        .collect()
+}
+
+/// Prints the trend of the selected days, or of all days. Returns the number of days that got
+/// slower than the best run by more than `threshold` percent.
+pub fn report(entries: &[Entry], days: Option<&[u32]>, recent: usize, threshold: f64) -> usize {
+    let trends: Vec<Trend> = trends(entries, recent).into_iter()
+        .filter(|t| days.is_none_or(|d| d.contains(&t.day)))
+        .collect();
+
+    let mut table = Table::new("{:<} {:<} {:<} {:<} {:<} {:<} {:<} {:<} {:<}");
+    table.add_row(row!("day", "runs", "best", "commit", "date", "latest", "commit", "change", "trend"));
+    let mut regressions = 0;
+    let total = |e: &Entry| e.total().map(|t| format!("{:.2?}", t)).unwrap_or_default();
+    for t in &trends {
+        let regression = t.is_regression(threshold);
+        regressions += regression as usize;
+        let trend = t.recent.iter()
+            .map(|e| total(e))
+            .collect::<Vec<_>>()
+            .join(" ");
+        table.add_row(row!(
+            t.day,
+            t.runs,
+            total(t.best),
+            t.best.revision(),
+            t.best.date.format("%Y-%m-%d"),
+            total(t.latest()),
+            t.latest().revision(),
+            t.change().map(|c| format!("{:+.1}%{}", c, if regression { " SLOWER" } else { "" })).unwrap_or_default(),
+            trend
+        ));
+    }
+    print!("{}", table);
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(commit: &str, day: u32, ms: u64) -> Entry {
+        Entry {
+            commit: commit.to_string(),
+            dirty: false,
+            date: Utc::now(),
+            release: RELEASE,
+            parallel: false,
+            alloc_stats: false,
+            contended: false,
+            day,
+            parse_ns: Some(0),
+            part1_ns: Some(ms * 1_000_000),
+            part2_ns: Some(0),
+            failed: false,
+        }
+    }
+
+    #[test]
+    fn regressions() {
+        let failed = Entry { failed: true, ..entry("d", 1, 1) };
+        let partial = Entry { part2_ns: None, ..entry("d", 1, 1) };
+        let contended = Entry { contended: true, ..entry("d", 1, 1) };
+        let debug = Entry { release: !RELEASE, ..entry("d", 1, 1) };
+        let parallel = |commit, day, ms| Entry { parallel: true, ..entry(commit, day, ms) };
+        let counted = Entry { alloc_stats: true, ..entry("s", 1, 1) };
+        let entries = vec![entry("a", 1, 10), entry("a", 2, 10), counted, entry("b", 1, 8), parallel("p", 2, 5),
+            entry("c", 1, 10), entry("c", 2, 10), failed, partial, contended, debug, entry("a", 3, 10), parallel("p", 3, 30)];
+
+        let trends = trends(&entries, 2);
+        assert_eq!(trends.len(), 3);
+        assert_eq!(trends[0].runs, 3);
+        assert_eq!(trends[0].best.commit, "b");
+        assert_eq!(trends[0].recent.iter().map(|e| e.commit.as_str()).collect::<Vec<_>>(), ["b", "c"]);
+        assert_eq!(trends[0].change(), Some(25.0));
+        assert!(trends[0].is_regression(10.0));
+        assert!(!trends[0].is_regression(30.0));
+        assert!(!trends[1].is_regression(0.0));
+        assert_eq!(trends[1].best.commit, "a");
+        // the latest run of day 3 was parallel, it is not compared with the sequential run
+        assert_eq!((trends[2].runs, trends[2].best.commit.as_str()), (1, "p"));
+
+        // a run that took no measurable time has no change to compare against
+        let instant = Entry { part1_ns: Some(0), ..entry("z", 4, 0) };
+        assert_eq!(Trend { day: 4, runs: 1, best: &instant, recent: vec![&instant] }.change(), None);
+        assert_eq!(super::trends(&[instant.clone(), entry("y", 4, 3)], 2).len(), 0);
+    }
+
+    #[test]
+    fn round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-history-{}.jsonl", std::process::id()));
+        let result = DayResult { day: 3, parse_time: Some(Duration::from_micros(5)), ..Default::default() };
+        record(&path, &[result.clone(), DayResult { day: 4, ..result }], false).unwrap();
+        record(&path, &[DayResult { day: 5, ..Default::default() }], true).unwrap();
+        // entries from before the run setup was recorded
+        let mut f = OpenOptions::new().append(true).open(&path).unwrap();
+        writeln!(f, r#"{{"commit":"abc","dirty":false,"date":"2023-12-01T05:00:00Z","release":true,"day":6,"parse_ns":1,"part1_ns":2,"part2_ns":3,"failed":false}}"#).unwrap();
+
+        let entries = load(&path).unwrap();
+        assert_eq!(entries.iter().map(|e| e.day).collect::<Vec<_>>(), [3, 4, 5, 6]);
+        assert_eq!(entries[0].parse_ns, Some(5000));
+        assert_eq!(entries[0].total(), None);
+        assert_eq!(entries.iter().map(|e| e.parallel).collect::<Vec<_>>(), [false, false, true, false]);
+        assert_eq!(entries[0].alloc_stats, alloc::ENABLED);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,227 @@
+use std::fs::{create_dir_all, read_to_string, rename, write};
+use std::path::Path;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const USER_AGENT: &str = concat!("github.com/veldsla/adventofcode/aoc-core/", env!("CARGO_PKG_VERSION"), " by avelds@gmail.com");
+
+/// Read from the working directory, never commit it, it holds the session token
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Settings for downloading inputs. They are read from `aoc.toml`, the `AOC_SESSION` and
+/// `AOC_BASE_URL` environment variables take precedence.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Value of the session cookie of a logged in browser
+    pub session: Option<String>,
+    pub base_url: String,
+    pub user_agent: String,
+    /// Minimum number of seconds between two requests
+    pub interval: f64,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            user_agent: USER_AGENT.to_string(),
+            interval: 5.0,
+        }
+    }
+}
+
+impl Config {
+    pub fn load() -> Result<Config> {
+        let mut config = if Path::new(CONFIG_FILE).exists() {
+            Config::parse(&read_to_string(CONFIG_FILE)?).with_context(|| format!("Invalid {}", CONFIG_FILE))?
+        } else {
+            Config::default()
+        };
+
+        if let Ok(session) = std::env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(url) = std::env::var("AOC_BASE_URL") {
+            config.base_url = url;
+        }
+        Ok(config)
+    }
+
+    pub fn parse(s: &str) -> Result<Config> {
+        Ok(toml::from_str(s)?)
+    }
+}
+
+/// Downloads inputs, waiting at least the configured interval between requests
+pub struct Fetcher {
+    config: Config,
+    agent: ureq::Agent,
+    last_request: Option<Instant>,
+}
+
+impl Fetcher {
+    pub fn new(config: Config) -> Fetcher {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(&config.user_agent)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Fetcher { config, agent, last_request: None }
+    }
+
+    pub fn url(&self, year: i32, day: u32) -> String {
+        format!("{}/{}/day/{}/input", self.config.base_url.trim_end_matches('/'), year, day)
+    }
+
+    fn wait(&mut self) {
+        let interval = Duration::from_secs_f64(self.config.interval.max(0.0));
+        if let Some(elapsed) = self.last_request.map(|t| t.elapsed()) {
+            if elapsed < interval {
+                sleep(interval - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+
+    fn session(&self) -> Result<String> {
+        self.config.session.as_ref()
+            .map(|s| format!("session={}", s.trim()))
+            .ok_or_else(|| anyhow!("No session token, set AOC_SESSION or session in {}", CONFIG_FILE))
+    }
+
+    /// Sends a GET, or a POST when there is a form, and returns the body of the response
+    fn call(&mut self, url: &str, form: Option<&[(&str, &str)]>) -> Result<String> {
+        let request = match form {
+            Some(_) => self.agent.post(url),
+            None => self.agent.get(url),
+        }.set("Cookie", &self.session()?);
+
+        self.wait();
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(anyhow!("Fetching {} failed with status {}: {}", url, code, body.trim()))
+            },
+            Err(e) => Err(anyhow!("Fetching {} failed: {}", url, e)),
+        }
+    }
+
+    /// Downloads the input of `day`
+    pub fn fetch(&mut self, year: i32, day: u32) -> Result<String> {
+        let url = self.url(year, day);
+        self.call(&url, None)
+    }
+
+    /// Posts `answer` to `part` of `day` and returns the response page
+    pub fn post_answer(&mut self, year: i32, day: u32, part: u8, answer: &str) -> Result<String> {
+        let url = format!("{}/{}/day/{}/answer", self.config.base_url.trim_end_matches('/'), year, day);
+        self.call(&url, Some(&[("level", &part.to_string()), ("answer", answer)]))
+    }
+
+    /// Downloads the input of `day` to `path` unless it is already there. Returns whether the
+    /// input was downloaded.
+    pub fn ensure<P: AsRef<Path>>(&mut self, year: i32, day: u32, path: P) -> Result<bool> {
+        let path = path.as_ref();
+        if path.exists() {
+            return Ok(false);
+        }
+
+        let input = self.fetch(year, day)?;
+        if let Some(dir) = path.parent() {
+            create_dir_all(dir)?;
+        }
+        // never leave a partial input behind
+        let part = path.with_extension("part");
+        write(&part, input)?;
+        rename(&part, path)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{channel, Receiver};
+
+    /// Answers `responses` requests with the given status and body, sends back the requests
+    pub(crate) fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = channel();
+        std::thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = String::new();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                while reader.read_line(&mut request).unwrap() > 2 {}
+                let length = request.lines()
+                    .find_map(|l| l.to_lowercase().strip_prefix("content-length: ").map(|n| n.parse().unwrap()))
+                    .unwrap_or(0);
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                tx.send(request).unwrap();
+                write!(stream, "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+            }
+        });
+        (url, rx)
+    }
+
+    pub(crate) fn config(base_url: String) -> Config {
+        Config { session: Some("abc".to_string()), base_url, interval: 0.2, ..Config::default() }
+    }
+
+    #[test]
+    fn fetch_and_cache() {
+        let (url, requests) = mock_server(vec![(200, "1\n2\n"), (200, "3\n")]);
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        let path = dir.join("day_01.txt");
+        let mut f = Fetcher::new(config(url));
+
+        assert!(f.ensure(2023, 1, &path).unwrap());
+        assert_eq!(read_to_string(&path).unwrap(), "1\n2\n");
+        let head = requests.recv().unwrap();
+        assert!(head.starts_with("GET /2023/day/1/input "));
+        assert!(head.contains("\r\nCookie: session=abc\r\n"));
+        assert!(head.to_lowercase().contains(&format!("user-agent: {}", USER_AGENT.to_lowercase())));
+
+        assert!(!f.ensure(2023, 1, &path).unwrap());
+
+        let start = Instant::now();
+        assert_eq!(f.fetch(2023, 2).unwrap(), "3\n");
+        assert!(start.elapsed() >= Duration::from_millis(150));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn errors() {
+        let (url, _requests) = mock_server(vec![(404, "Not found\n")]);
+        let mut f = Fetcher::new(config(url));
+        let e = f.fetch(2023, 25).unwrap_err().to_string();
+        assert!(e.ends_with("failed with status 404: Not found"), "{}", e);
+
+        let mut f = Fetcher::new(Config::default());
+        assert!(f.fetch(2023, 1).is_err());
+    }
+
+    #[test]
+    fn parse_config() {
+        let c = Config::parse("session = \"s\"\nbase_url = \"http://localhost:8000/\"\n").unwrap();
+        assert_eq!(c.session.as_deref(), Some("s"));
+        assert_eq!(c.user_agent, USER_AGENT);
+        assert_eq!(Fetcher::new(c).url(2020, 3), "http://localhost:8000/2020/day/3/input");
+        assert!(Config::parse("sesion = \"s\"").is_err());
+    }
+}
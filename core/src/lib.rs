@@ -0,0 +1,121 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Result};
+
+pub trait Problem {
+    #[allow(unused_variables)]
+    fn parse(&mut self, i: &str) -> Result<()> {
+        Err(anyhow!("Parser not yet implemented"))
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Err(anyhow!("Part 1 not yet implemented"))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Err(anyhow!("Part 2 not yet implemented"))
+    }
+}
+
+/// A solution that keeps the parsed input separate from the solution. The input can be parsed
+/// once and shared between both parts. Use [`Parsed`] to run it as a [`Problem`].
+pub trait Solver {
+    type Input;
+
+    fn parse(i: &str) -> Result<Self::Input>;
+
+    #[allow(unused_variables)]
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Err(anyhow!("Part 1 not yet implemented"))
+    }
+
+    #[allow(unused_variables)]
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Err(anyhow!("Part 2 not yet implemented"))
+    }
+}
+
+/// Adapter that runs a [`Solver`] as a [`Problem`] by holding on to the parsed input
+pub struct Parsed<S: Solver> {
+    input: Option<S::Input>,
+}
+
+impl<S: Solver> Default for Parsed<S> {
+    fn default() -> Self {
+        Parsed { input: None }
+    }
+}
+
+impl<S: Solver> Parsed<S> {
+    fn input(&self) -> Result<&S::Input> {
+        self.input.as_ref().ok_or_else(|| anyhow!("Input not parsed"))
+    }
+}
+
+impl<S: Solver> Problem for Parsed<S> {
+    fn parse(&mut self, i: &str) -> Result<()> {
+        self.input = Some(S::parse(i)?);
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        S::part1(self.input()?)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        S::part2(self.input()?)
+    }
+}
+
+/// Constructs the solution of a day
+pub type SolutionFn = fn(u32) -> Result<Box<dyn Problem>>;
+
+/// The solutions of a single year. Its inputs and sources are found in the directory named after
+/// the year.
+#[derive(Debug, Clone, Copy)]
+pub struct Year {
+    pub year: i32,
+    pub get_solution: SolutionFn,
+}
+
+impl Year {
+    pub fn input_path(&self, day: u32) -> PathBuf {
+        PathBuf::from(format!("{}/inputs/day_{:02}.txt", self.year, day))
+    }
+
+    pub fn days_dir(&self) -> PathBuf {
+        PathBuf::from(format!("{}/src/days", self.year))
+    }
+
+    /// A puzzle page saved from the site, the source of the examples
+    pub fn puzzle_path(&self, day: u32) -> PathBuf {
+        PathBuf::from(format!("{}/puzzles/day_{:02}.html", self.year, day))
+    }
+
+    pub fn examples_dir(&self) -> PathBuf {
+        PathBuf::from(format!("{}/examples", self.year))
+    }
+
+    /// Timings of earlier runs
+    pub fn history_path(&self) -> PathBuf {
+        PathBuf::from(format!("{}/history.jsonl", self.year))
+    }
+}
+
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+pub mod examples;
+pub mod history;
+pub mod parsers;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod submit;
+pub mod grid;
+pub mod inputs;
+pub mod vm;
+
+pub use answers::Answer;
+pub use runner::{run_all, run_day, run_days, DayList, RunOptions};
@@ -0,0 +1,560 @@
+use std::fmt;
+use std::num::ParseIntError;
+use std::ops::Range;
+
+#[allow(unused)]
+use nom::{
+    branch::alt,
+    character::complete::{char, digit1, line_ending, multispace0},
+    combinator::{all_consuming, map, map_res, opt, recognize},
+    error::{ErrorKind, FromExternalError, VerboseError, VerboseErrorKind},
+    multi::{many0, many1, separated_list1},
+    sequence::{pair, preceded, separated_pair, terminated, tuple},
+    AsBytes, AsChar, IResult, InputIter, InputLength, Parser, Slice,
+    lib::std::ops::{RangeFrom, RangeTo}
+};
+
+pub mod record;
+
+/// Result of parsers that keep track of their context, see [`ParseError`]
+pub type VResult<'a, O> = IResult<&'a str, O, VerboseError<&'a str>>;
+
+/// Error types that can be reported as a [`ParseError`]
+pub trait IntoVerbose<'a> {
+    fn into_verbose(self) -> VerboseError<&'a str>;
+}
+
+impl<'a> IntoVerbose<'a> for VerboseError<&'a str> {
+    fn into_verbose(self) -> VerboseError<&'a str> {
+        self
+    }
+}
+
+impl<'a> IntoVerbose<'a> for nom::error::Error<&'a str> {
+    fn into_verbose(self) -> VerboseError<&'a str> {
+        VerboseError { errors: vec![(self.input, VerboseErrorKind::Nom(self.code))] }
+    }
+}
+
+/// A failed parse, located in the input. The contexts are the names given to the enclosing
+/// parsers with `nom::error::context`, outermost first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line and column of the failure, both counting from 1
+    pub position: Option<(usize, usize)>,
+    pub line: String,
+    pub reason: String,
+    pub context: Vec<&'static str>,
+}
+
+impl ParseError {
+    /// Locates the error `e` of a parser that was given `input`, or a part of it
+    pub fn new<'a, E: IntoVerbose<'a>>(input: &'a str, e: nom::Err<E>) -> ParseError {
+        let e = match e {
+            nom::Err::Error(e) | nom::Err::Failure(e) => e.into_verbose(),
+            nom::Err::Incomplete(_) => {
+                return ParseError { position: None, line: String::new(), reason: "incomplete input".to_string(), context: Vec::new() };
+            },
+        };
+
+        let reason = e.errors.iter()
+            .find_map(|(_, kind)| match kind {
+                VerboseErrorKind::Char(c) => Some(format!("expected '{}'", c.escape_default())),
+                VerboseErrorKind::Nom(ErrorKind::Eof) => Some("unexpected input after the end".to_string()),
+                VerboseErrorKind::Nom(ErrorKind::MapRes) => Some("invalid value".to_string()),
+                VerboseErrorKind::Nom(k) => Some(format!("{} did not match", k.description())),
+                VerboseErrorKind::Context(_) => None,
+            })
+            .unwrap_or_else(|| "failed".to_string());
+        let context = e.errors.iter().rev()
+            .filter_map(|(_, kind)| match kind {
+                VerboseErrorKind::Context(c) => Some(*c),
+                _ => None,
+            })
+            .collect();
+
+        // the remaining input of the innermost error, when it points into the input
+        let offset = e.errors.first()
+            .map(|(rest, _)| (rest.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize))
+            .filter(|&o| o <= input.len() && input.is_char_boundary(o));
+        let (position, line) = match offset {
+            Some(o) => {
+                let start = input[..o].rfind('\n').map_or(0, |i| i + 1);
+                let end = input[o..].find('\n').map_or(input.len(), |i| o + i);
+                let line = input[start..end].trim_end_matches('\r').to_string();
+                let row = input[..o].matches('\n').count() + 1;
+                (Some((row, input[start..o].chars().count() + 1)), line)
+            },
+            None => (None, String::new()),
+        };
+
+        ParseError { position, line, reason, context }
+    }
+}
+
+/// The failing line is shown with a caret under the column
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.position {
+            Some((row, column)) => {
+                write!(f, "parse error at line {}, column {}: {}", row, column, self.reason)?;
+                let margin = row.to_string().len();
+                write!(f, "\n{} | {}", row, self.line)?;
+                write!(f, "\n{:margin$} | {:>column$}", "", "^", margin = margin, column = column)?;
+            },
+            None => write!(f, "parse error: {}", self.reason)?,
+        }
+        if !self.context.is_empty() {
+            write!(f, "\nwhile parsing {}", self.context.join(" > "))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs `parser` on all of `input`, only trailing whitespace may remain
+pub fn parse_all<'a, O, E, F>(input: &'a str, parser: F) -> Result<O, ParseError>
+where
+    F: Parser<&'a str, O, E>,
+    E: nom::error::ParseError<&'a str> + IntoVerbose<'a>,
+{
+    all_consuming(terminated(parser, multispace0))(input)
+        .map(|(_, o)| o)
+        .map_err(|e| ParseError::new(input, e))
+}
+
+/// The primitive integer types, see [`unsigned`], [`signed`] and [`integer`]
+pub trait Integer: Sized + Copy {
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError>;
+}
+
+macro_rules! integer_impl {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            fn from_str_radix(s: &str, radix: u32) -> Result<$t, ParseIntError> {
+                <$t>::from_str_radix(s, radix)
+            }
+        })*
+    };
+}
+
+integer_impl!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Input of the parsers that take `&str` as well as `&[u8]`
+pub trait Input: Clone + AsBytes + InputLength + Slice<RangeFrom<usize>> + Slice<RangeTo<usize>> {}
+
+impl<T> Input for T where T: Clone + AsBytes + InputLength + Slice<RangeFrom<usize>> + Slice<RangeTo<usize>> {}
+
+/// Length of the number at the start of `b`, 0 when there are no digits
+fn number_len(b: &[u8], signs: &[u8]) -> usize {
+    let sign = b.first().is_some_and(|c| signs.contains(c)) as usize;
+    match b[sign..].iter().take_while(|c| c.is_ascii_digit()).count() {
+        0 => 0,
+        n => sign + n,
+    }
+}
+
+fn decimal<I: Input, N, E>(i: I, signs: &[u8]) -> IResult<I, N, E>
+where
+    N: Integer,
+    E: nom::error::ParseError<I> + FromExternalError<I, ParseIntError>,
+{
+    let b = i.as_bytes();
+    let len = number_len(b, signs);
+    if len == 0 {
+        return Err(nom::Err::Error(E::from_error_kind(i, ErrorKind::Digit)));
+    }
+    match to_integer(&b[..len]) {
+        Ok(n) => Ok((i.slice(len..), n)),
+        Err(e) => Err(nom::Err::Error(E::from_external_error(i, ErrorKind::MapRes, e))),
+    }
+}
+
+/// Decimal digits with an optional `+`. A number that does not fit `N` is a `MapRes` error.
+pub fn unsigned<I: Input, N, E>(i: I) -> IResult<I, N, E>
+where
+    N: Integer,
+    E: nom::error::ParseError<I> + FromExternalError<I, ParseIntError>,
+{
+    decimal(i, b"+")
+}
+
+/// Decimal digits with an optional sign, negative numbers are an error for unsigned types
+pub fn signed<I: Input, N, E>(i: I) -> IResult<I, N, E>
+where
+    N: Integer,
+    E: nom::error::ParseError<I> + FromExternalError<I, ParseIntError>,
+{
+    decimal(i, b"+-")
+}
+
+/// Radix and length of the `0x`, `0b` or `0o` prefix at the start of `b`, when digits follow it
+fn radix_prefix(b: &[u8]) -> (u32, usize) {
+    let radix = match b {
+        [b'0', b'x' | b'X', ..] => 16,
+        [b'0', b'b' | b'B', ..] => 2,
+        [b'0', b'o' | b'O', ..] => 8,
+        _ => return (10, 0),
+    };
+    match b.get(2) {
+        Some(&c) if char::from(c).is_digit(radix) => (radix, 2),
+        _ => (10, 0),
+    }
+}
+
+/// An integer written like a Rust literal: `-42`, `0x1F`, `0b1010_0101`, `0o17` or `1_000_000`.
+/// Underscores can follow the first digit.
+pub fn integer<I: Input, N, E>(i: I) -> IResult<I, N, E>
+where
+    N: Integer,
+    E: nom::error::ParseError<I> + FromExternalError<I, ParseIntError>,
+{
+    let b = i.as_bytes();
+    let sign = matches!(b.first(), Some(b'+' | b'-')) as usize;
+    let (radix, prefix) = radix_prefix(&b[sign..]);
+    let start = sign + prefix;
+    let is_digit = |c: &u8| char::from(*c).is_digit(radix);
+    if !b.get(start).is_some_and(is_digit) {
+        return Err(nom::Err::Error(E::from_error_kind(i, ErrorKind::Digit)));
+    }
+    let len = start + b[start..].iter().take_while(|&c| is_digit(c) || *c == b'_').count();
+
+    let mut number = String::with_capacity(len);
+    number.extend((b[0] == b'-').then_some('-'));
+    number.extend(b[start..len].iter().filter(|&&c| c != b'_').map(|&c| char::from(c)));
+    match N::from_str_radix(&number, radix) {
+        Ok(n) => Ok((i.slice(len..), n)),
+        Err(e) => Err(nom::Err::Error(E::from_external_error(i, ErrorKind::MapRes, e))),
+    }
+}
+
+fn newline_len(b: &[u8]) -> Option<usize> {
+    match b {
+        [b'\n', ..] => Some(1),
+        [b'\r', b'\n', ..] => Some(2),
+        _ => None,
+    }
+}
+
+/// A `\n` or `\r\n` line ending
+pub fn newline<I: Input, E: nom::error::ParseError<I>>(i: I) -> IResult<I, (), E> {
+    match newline_len(i.as_bytes()) {
+        Some(n) => Ok((i.slice(n..), ())),
+        None => Err(nom::Err::Error(E::from_error_kind(i, ErrorKind::CrLf))),
+    }
+}
+
+/// The end of a line followed by one or more empty lines
+pub fn blank_lines<I: Input, E: nom::error::ParseError<I>>(i: I) -> IResult<I, (), E> {
+    let (mut rest, _) = newline(i.clone())?;
+    let mut blank = 0;
+    while let Some(n) = newline_len(rest.as_bytes()) {
+        rest = rest.slice(n..);
+        blank += 1;
+    }
+    match blank {
+        0 => Err(nom::Err::Error(E::from_error_kind(i, ErrorKind::CrLf))),
+        _ => Ok((rest, ())),
+    }
+}
+
+/// One or more lines matched by `f`, ending before the first line it does not match. `f` should
+/// not match an empty line, or the list runs on into the next section.
+pub fn lines<I, O, E, F>(f: F) -> impl FnMut(I) -> IResult<I, Vec<O>, E>
+where
+    I: Input,
+    E: nom::error::ParseError<I>,
+    F: Parser<I, O, E>,
+{
+    separated_list1(newline, f)
+}
+
+/// Sections separated by empty lines, each matched by `f`. The line ending of the last line of
+/// the last section is not consumed.
+///
+/// ```ignore
+/// // groups of lines
+/// sections(lines(alpha1))
+/// ```
+pub fn sections<I, O, E, F>(f: F) -> impl FnMut(I) -> IResult<I, Vec<O>, E>
+where
+    I: Input,
+    E: nom::error::ParseError<I>,
+    F: Parser<I, O, E>,
+{
+    separated_list1(blank_lines, f)
+}
+
+/// Length of the spaces and tabs at the start of `b`
+fn blank_len(b: &[u8]) -> usize {
+    b.iter().take_while(|&&c| c == b' ' || c == b'\t').count()
+}
+
+/// Numbers on a single line separated by spaces, tabs or commas, leading blanks are skipped. The
+/// number of items is not fixed, so lines of a list can differ in length.
+pub fn number_list<I: Input, N, E>(i: I) -> IResult<I, Vec<N>, E>
+where
+    N: Integer,
+    E: nom::error::ParseError<I> + FromExternalError<I, ParseIntError>,
+{
+    let (mut rest, n) = signed(i.slice(blank_len(i.as_bytes())..))?;
+    let mut list = vec![n];
+    loop {
+        let b = rest.as_bytes();
+        let mut sep = blank_len(b);
+        if b.get(sep) == Some(&b',') {
+            sep += 1 + blank_len(&b[sep + 1..]);
+        }
+        if sep == 0 || number_len(&b[sep..], b"+-") == 0 {
+            return Ok((rest, list));
+        }
+        let (r, n) = signed(rest.slice(sep..))?;
+        list.push(n);
+        rest = r;
+    }
+}
+
+/// Lines of [`number_list`], a list of rows of different lengths
+pub fn number_rows<I: Input, N, E>(i: I) -> IResult<I, Vec<Vec<N>>, E>
+where
+    N: Integer,
+    E: nom::error::ParseError<I> + FromExternalError<I, ParseIntError>,
+{
+    lines(number_list)(i)
+}
+
+/// Positions of the numbers in free text. A `-` is a sign when a digit follows it and no digit
+/// precedes it, so `x=-3` holds -3 but the range `1-3` holds 1 and 3.
+fn find_numbers(b: &[u8]) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut pos = 0;
+    std::iter::from_fn(move || {
+        while pos < b.len() {
+            let start = pos;
+            let sign = b[pos] == b'-' && (pos == 0 || !b[pos - 1].is_ascii_digit());
+            let len = number_len(&b[pos..], if sign { b"-" } else { b"" });
+            if len > 0 {
+                pos += len;
+                return Some(start..pos);
+            }
+            pos += 1;
+        }
+        None
+    })
+}
+
+/// Converts a match of [`number_len`], which is always ascii
+fn to_integer<N: Integer>(b: &[u8]) -> Result<N, ParseIntError> {
+    N::from_str_radix(std::str::from_utf8(b).unwrap(), 10)
+}
+
+/// Every integer in `text`, whatever surrounds them. Fails when a number does not fit `N`.
+///
+/// ```ignore
+/// assert_eq!(integers::<i64, _>("Sensor at x=2, y=-18: closest beacon is at x=-2, y=15")?, [2, -18, -2, 15]);
+/// ```
+pub fn integers<N: Integer, T: AsRef<[u8]>>(text: T) -> Result<Vec<N>, ParseIntError> {
+    let b = text.as_ref();
+    find_numbers(b).map(|r| to_integer(&b[r])).collect()
+}
+
+/// Every integer on the rest of the line, see [`integers`]. The line ending is not consumed. A
+/// line without numbers is a `Digit` error, a number that does not fit `N` is a `MapRes` error
+/// at that number.
+pub fn line_integers<I: Input, N, E>(i: I) -> IResult<I, Vec<N>, E>
+where
+    N: Integer,
+    E: nom::error::ParseError<I> + FromExternalError<I, ParseIntError>,
+{
+    let b = i.as_bytes();
+    let end = b.iter().position(|&c| c == b'\n').map_or(b.len(), |p| p - (p > 0 && b[p - 1] == b'\r') as usize);
+    let list = find_numbers(&b[..end])
+        .map(|r| to_integer(&b[r.clone()]).map_err(|e| nom::Err::Error(E::from_external_error(i.slice(r.start..), ErrorKind::MapRes, e))))
+        .collect::<Result<Vec<N>, _>>()?;
+    if list.is_empty() {
+        return Err(nom::Err::Error(E::from_error_kind(i, ErrorKind::Digit)));
+    }
+    Ok((i.slice(end..), list))
+}
+
+/// Decimal digits with an optional `-`, unlike [`signed`] a leading `+` is not accepted
+pub fn parse_i32(i: &str) -> IResult<&str, i32> {
+    decimal(i, b"-")
+}
+
+pub fn positive_integer<N: Integer>(i: &str) -> IResult<&str, N> {
+    unsigned(i)
+}
+
+pub fn negative_integer<N: Integer>(i: &str) -> IResult<&str, N> {
+    map_res(recognize(pair(char('-'), digit1)), |d: &str| N::from_str_radix(d, 10))(i)
+}
+
+pub fn signed_integer<N: Integer>(i: &str) -> IResult<&str, N> {
+    signed(i)
+}
+
+pub fn range_positive_integer<N: Integer>(i: &str) -> IResult<&str, std::ops::RangeInclusive<N>> {
+    map(separated_pair(positive_integer, char('-'), positive_integer), |(b, e)| b..=e)(i)
+}
+
+pub fn commasep_positive_integer<N: Integer>(i: &str) -> IResult<&str, Vec<N>> {
+    separated_list1(char(','), positive_integer)(i)
+}
+
+pub fn int_range_inclusive(i: &str) -> IResult<&str, Range<i32>> {
+    map(separated_pair(parse_i32, char('-'), parse_i32), |(start, stop)| start..stop+1)(i)
+}
+
+pub fn numbers_on_lines(i: &str) -> IResult<&str, Vec<i32>> {
+    many1(terminated(parse_i32, many0(line_ending)))(i)
+    //separated_list1(line_ending, parse_i32)(i)
+}
+
+pub fn single_dec_digit(i: &str) -> IResult<&str, u32> {
+    if let Some(c) = i.chars().next() {
+        if c.is_ascii_digit() {
+            return Ok((&i[1..], c.to_digit(10).unwrap()))
+        }
+    }
+    Err(nom::Err::Error(nom::error::Error::new(i, nom::error::ErrorKind::Digit)))
+}
+
+pub fn single_alpha(i: &str) -> IResult<&str, char> {
+    if let Some(c) = i.chars().next() {
+        if c.is_alpha() {
+            return Ok((&i[1..], c))
+        }
+    }
+    Err(nom::Err::Error(nom::error::Error::new(i, nom::error::ErrorKind::Digit)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::bytes::complete::tag;
+    use nom::character::complete::alpha1;
+    use nom::combinator::cut;
+    use nom::error::context;
+
+    fn assignment(i: &str) -> VResult<'_, (&str, i32)> {
+        context("assignment", separated_pair(alpha1, tag(" = "), context("value", nom::character::complete::i32)))(i)
+    }
+
+    type E<'a> = nom::error::Error<&'a str>;
+
+    #[test]
+    fn checked() {
+        assert_eq!(unsigned::<_, u8, E>("255,"), Ok((",", 255)));
+        assert_eq!(unsigned::<_, u8, E>("256"), Err(nom::Err::Error(nom::error::Error::new("256", ErrorKind::MapRes))));
+        assert!(unsigned::<_, u64, E>("-1").is_err());
+        assert_eq!(signed::<_, i64, E>("-9223372036854775808"), Ok(("", i64::MIN)));
+        assert!(signed::<_, u16, E>("-1").is_err());
+        assert_eq!(signed::<_, i128, E>("+170141183460469231731687303715884105727"), Ok(("", i128::MAX)));
+        assert!(signed::<_, i128, E>("170141183460469231731687303715884105728").is_err());
+    }
+
+    #[test]
+    fn literals() {
+        assert_eq!(integer::<_, u32, E>("0x1F a"), Ok((" a", 31)));
+        assert_eq!(integer::<_, i16, E>("-0b1000_0000_0000_0000"), Ok(("", i16::MIN)));
+        assert_eq!(integer::<_, u8, E>("0o17"), Ok(("", 15)));
+        assert_eq!(integer::<_, u64, E>("1_000_000"), Ok(("", 1_000_000)));
+        assert_eq!(integer::<_, isize, E>("+7_"), Ok(("", 7)));
+        assert_eq!(integer::<_, u8, E>("0b12"), Ok(("2", 1)));
+        assert!(integer::<_, u8, E>("0x100").is_err());
+        assert!(integer::<_, u8, E>("-0x1").is_err());
+        assert!(integer::<_, u8, E>("_1").is_err());
+        assert_eq!(integer::<_, u8, E>("0xg"), Ok(("xg", 0)));
+        assert_eq!(integer::<_, i32, nom::error::Error<&[u8]>>(b"-0x1_F,".as_slice()), Ok((b",".as_slice(), -31)));
+
+        // overflow is reported at the number
+        let e = parse_all("a = 0x1_00", context("assignment", separated_pair(alpha1, tag(" = "), integer::<_, u8, VerboseError<&str>>))).unwrap_err();
+        assert_eq!((e.position, e.reason.as_str()), (Some((1, 5)), "invalid value"));
+    }
+
+    #[test]
+    fn layout() {
+        let input = "1 2 3\n4\n\n\n5,6, -7\n\n8\t9\n";
+        let expected = vec![vec![vec![1, 2, 3], vec![4]], vec![vec![5, 6, -7]], vec![vec![8, 9]]];
+        assert_eq!(sections(number_rows::<_, i32, E>)(input), Ok(("\n", expected.clone())));
+        let bytes: IResult<&[u8], Vec<Vec<Vec<i32>>>> = sections(number_rows)(input.as_bytes());
+        assert_eq!(bytes, Ok((&b"\n"[..], expected)));
+        assert_eq!(parse_all("a\r\nb\r\n\r\nc\r\n", sections(lines(alpha1::<_, E>))), Ok(vec![vec!["a", "b"], vec!["c"]]));
+
+        // a list ends before a separator that is not followed by a number
+        assert_eq!(number_list::<_, u8, E>("  1, 2 ,3 x"), Ok((" x", vec![1, 2, 3])));
+        assert_eq!(number_list::<_, u8, E>("1,\n2"), Ok((",\n2", vec![1])));
+        assert!(number_list::<_, u8, E>("1 300").is_err());
+        assert!(blank_lines::<_, E>("\nx").is_err());
+    }
+
+    #[test]
+    fn free_text() {
+        assert_eq!(integers::<i64, _>("Sensor at x=2, y=-18: closest beacon is at x=-2, y=15"), Ok(vec![2, -18, -2, 15]));
+        assert_eq!(integers::<u32, _>(b"move 1-3 to 12".as_slice()), Ok(vec![1, 3, 12]));
+        assert_eq!(integers::<i8, _>("--5 a-6"), Ok(vec![-5, -6]));
+        assert!(integers::<u8, _>("1 256").is_err());
+        assert_eq!(integers::<u8, _>("none"), Ok(vec![]));
+
+        assert_eq!(line_integers::<_, i32, E>("p=<-1,2> v=<3,-4>\r\nnext 5"), Ok(("\r\nnext 5", vec![-1, 2, 3, -4])));
+        assert_eq!(lines(line_integers::<_, u64, nom::error::Error<&[u8]>>)(b"a 1\nb\n".as_slice()), Ok((&b"\nb\n"[..], vec![vec![1]])));
+        let e = parse_all("x=1\ny=12345", separated_list1(line_ending, cut(line_integers::<_, u8, VerboseError<&str>>))).unwrap_err();
+        assert_eq!((e.position, e.reason.as_str()), (Some((2, 3)), "invalid value"));
+    }
+
+    #[test]
+    fn errors() {
+        let input = "a = 1\nbc = 23\nd = x\n";
+        // without cut the list ends before the bad line
+        let e = parse_all(input, separated_list1(line_ending, assignment)).unwrap_err();
+        assert_eq!((e.position, e.reason.as_str()), (Some((3, 1)), "unexpected input after the end"));
+
+        let e = parse_all(input, separated_list1(line_ending, cut(assignment))).unwrap_err();
+        assert_eq!(e.position, Some((3, 5)));
+        assert_eq!(e.context, ["assignment", "value"]);
+        assert_eq!(e.to_string(), "parse error at line 3, column 5: Digit did not match\n3 | d = x\n  |     ^\nwhile parsing assignment > value");
+
+        let e = parse_all("a = 1,", assignment).unwrap_err();
+        assert_eq!(e.to_string(), "parse error at line 1, column 6: unexpected input after the end\n1 | a = 1,\n  |      ^");
+
+        // default errors only have a position
+        let e = ParseError::new("12\n3a", tuple((parse_i32, line_ending, parse_i32, line_ending))("12\n3a").unwrap_err());
+        assert_eq!((e.position, e.reason.as_str()), (Some((2, 2)), "CrLf did not match"));
+
+        assert_eq!(parse_all("a = 1\n\n", assignment), Ok(("a", 1)));
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(positive_integer::<u32>("123"), Ok(("", 123)));
+        //is allowed but will never be negatve
+        assert_eq!(positive_integer::<i32>("123"), Ok(("", 123)));
+        //negative fails to parse
+        assert!(positive_integer::<i32>("-123").is_err());
+        // overflow fails
+        assert!(positive_integer::<u8>("300").is_err());
+
+        assert_eq!(negative_integer::<i32>("-123"), Ok(("", -123)));
+        //positive numbers fail
+        assert!(negative_integer::<i32>("123").is_err());
+        // overflow fails
+        assert!(negative_integer::<i8>("-300").is_err());
+
+        //signed integers can be parsed into any signed type
+        assert_eq!(signed_integer::<i32>("123"), Ok(("", 123)));
+        assert_eq!(signed_integer::<i32>("-123"), Ok(("", -123)));
+        assert_eq!(signed_integer::<i32>("+123"), Ok(("", 123)));
+        assert!(signed_integer::<i8>("-300").is_err());
+        assert!(signed_integer::<i8>("300").is_err());
+
+        // the full range of a type fits
+        assert_eq!(signed_integer::<i8>("-128"), Ok(("", -128)));
+        assert_eq!(negative_integer::<i8>("-128"), Ok(("", -128)));
+        assert_eq!(parse_i32("-2147483648"), Ok(("", i32::MIN)));
+        assert!(parse_i32("2147483648").is_err());
+        assert!(parse_i32("+1").is_err());
+        assert_eq!(positive_integer::<u128>("340282366920938463463374607431768211455"), Ok(("", u128::MAX)));
+
+    }
+}
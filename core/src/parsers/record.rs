@@ -0,0 +1,315 @@
+//! Line formats described by a template like `"Card {id}: {winning:list} | {have:list}"`.
+//!
+//! Text outside the braces has to match literally, where a space matches any run of spaces and
+//! tabs, including none. `{{` and `}}` are literal braces. A field is written as `{name}` or
+//! `{name:kind}`:
+//!
+//! * `int` is an integer with an optional sign
+//! * `word` is a run of letters, digits and underscores
+//! * `token` is a run of anything but whitespace
+//! * `text` (the default) and `list` take everything up to the next literal, or the end of the
+//!   line for the last field. Surrounding whitespace is dropped.
+//!
+//! The type of the struct field or closure argument decides how a field is converted, see
+//! [`FromField`]. Use the [`record!`](crate::record) macro to build a nom parser.
+
+use nom::{
+    character::complete::{digit1, one_of},
+    bytes::complete::take_while1,
+    combinator::{opt, recognize},
+    error::{ContextError, ErrorKind, ParseError, VerboseError},
+    sequence::pair,
+};
+
+use super::VResult;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Int,
+    Word,
+    Token,
+    Text,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Literal(String),
+    Field { name: &'static str, kind: Kind },
+}
+
+/// A compiled line format
+#[derive(Debug, Clone)]
+pub struct Template {
+    parts: Vec<Part>,
+}
+
+impl Template {
+    /// Compiles `template`. Templates are part of the code, so an invalid template panics.
+    pub fn new(template: &'static str) -> Template {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut rest = template;
+        while let Some(c) = rest.chars().next() {
+            if let Some(r) = rest.strip_prefix("{{").or_else(|| rest.strip_prefix("}}")) {
+                literal.push(c);
+                rest = r;
+            } else if c == '{' {
+                let end = rest.find('}').unwrap_or_else(|| panic!("Unclosed field in template {:?}", template));
+                let (name, kind) = match rest[1..end].split_once(':') {
+                    Some((name, kind)) => (name, kind),
+                    None => (&rest[1..end], "text"),
+                };
+                let kind = match kind {
+                    "int" => Kind::Int,
+                    "word" => Kind::Word,
+                    "token" => Kind::Token,
+                    "text" | "list" => Kind::Text,
+                    _ => panic!("Unknown field kind {:?} in template {:?}", kind, template),
+                };
+                if name.is_empty() || parts.iter().any(|p| matches!(p, Part::Field { name: n, .. } if *n == name)) {
+                    panic!("Fields need a unique name in template {:?}", template);
+                }
+                if !literal.is_empty() {
+                    parts.push(Part::Literal(std::mem::take(&mut literal)));
+                } else if let Some(Part::Field { kind: Kind::Text, .. }) = parts.last() {
+                    panic!("A text field has to be followed by a literal in template {:?}", template);
+                }
+                parts.push(Part::Field { name, kind });
+                rest = &rest[end + 1..];
+            } else {
+                literal.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        if !literal.is_empty() {
+            parts.push(Part::Literal(literal));
+        }
+        Template { parts }
+    }
+
+    /// Compiles `template` and checks that its fields are exactly `fields`
+    pub fn with_fields(template: &'static str, fields: &[&str]) -> Template {
+        let t = Template::new(template);
+        let mut names: Vec<&str> = t.fields().collect();
+        let mut expected = fields.to_vec();
+        names.sort_unstable();
+        expected.sort_unstable();
+        if names != expected {
+            panic!("Template {:?} has fields {:?}, expected {:?}", template, names, expected);
+        }
+        t
+    }
+
+    pub fn fields(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.parts.iter().filter_map(|p| match p {
+            Part::Field { name, .. } => Some(*name),
+            Part::Literal(_) => None,
+        })
+    }
+
+    /// Matches a line, the fields are converted with [`Record::get`]
+    pub fn parse<'a>(&self, i: &'a str) -> VResult<'a, Record<'a>> {
+        let mut rest = i;
+        let mut values = Vec::new();
+        for (n, part) in self.parts.iter().enumerate() {
+            match part {
+                Part::Literal(l) => rest = literal(rest, l)?.0,
+                Part::Field { name, kind } => {
+                    let (r, value) = match kind {
+                        Kind::Int => recognize(pair(opt(one_of("+-")), digit1))(rest),
+                        Kind::Word => take_while1(|c: char| c.is_alphanumeric() || c == '_')(rest),
+                        Kind::Token => take_while1(|c: char| !c.is_whitespace())(rest),
+                        Kind::Text => match self.parts.get(n + 1) {
+                            Some(Part::Literal(next)) => until_literal(rest, next),
+                            _ => {
+                                let end = rest.find(['\r', '\n']).unwrap_or(rest.len());
+                                Ok((&rest[end..], &rest[..end]))
+                            },
+                        },
+                    }.map_err(|e| e.map(|e| VerboseError::add_context(rest, name, e)))?;
+                    values.push((*name, value.trim()));
+                    rest = r;
+                },
+            }
+        }
+        Ok((rest, Record { values }))
+    }
+}
+
+/// Matches `l`, a space in `l` matches any number of spaces and tabs
+fn literal<'a>(i: &'a str, l: &str) -> VResult<'a, ()> {
+    let mut rest = i;
+    for c in l.chars() {
+        if c == ' ' {
+            rest = rest.trim_start_matches([' ', '\t']);
+        } else if let Some(r) = rest.strip_prefix(c) {
+            rest = r;
+        } else {
+            return Err(nom::Err::Error(VerboseError::from_char(rest, c)));
+        }
+    }
+    Ok((rest, ()))
+}
+
+/// The text up to the first place on the line where `l` matches
+fn until_literal<'a>(i: &'a str, l: &str) -> VResult<'a, &'a str> {
+    let line_end = i.find(['\r', '\n']).unwrap_or(i.len());
+    i[..line_end].char_indices().map(|(p, _)| p).chain(std::iter::once(line_end))
+        .find(|&p| literal(&i[p..], l).is_ok())
+        .map(|p| (&i[p..], &i[..p]))
+        .ok_or_else(|| {
+            let expected = l.trim_start().chars().next().unwrap_or(' ');
+            nom::Err::Error(VerboseError::from_char(&i[line_end..], expected))
+        })
+}
+
+/// The fields of a matched line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record<'a> {
+    values: Vec<(&'static str, &'a str)>,
+}
+
+impl<'a> Record<'a> {
+    /// The text of a field
+    pub fn text(&self, name: &str) -> Option<&'a str> {
+        self.values.iter().find(|(n, _)| *n == name).map(|(_, v)| *v)
+    }
+
+    /// Converts a field, failing with the location of the field when the text does not fit `T`
+    pub fn get<T: FromField>(&self, name: &str) -> Result<T, nom::Err<VerboseError<&'a str>>> {
+        let (name, value) = *self.values.iter().find(|(n, _)| *n == name)
+            .unwrap_or_else(|| panic!("No field {} in the template", name));
+        T::from_field(value).ok_or_else(|| {
+            let e = VerboseError::from_error_kind(value, ErrorKind::MapRes);
+            nom::Err::Failure(VerboseError::add_context(value, name, e))
+        })
+    }
+}
+
+/// Conversion of the text of a field
+pub trait FromField: Sized {
+    fn from_field(s: &str) -> Option<Self>;
+}
+
+macro_rules! from_field_str {
+    ($($t:ty),*) => {
+        $(impl FromField for $t {
+            fn from_field(s: &str) -> Option<$t> {
+                s.parse().ok()
+            }
+        })*
+    };
+}
+
+from_field_str!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, char, String);
+
+/// Items separated by commas, semicolons or whitespace
+impl<T: FromField> FromField for Vec<T> {
+    fn from_field(s: &str) -> Option<Vec<T>> {
+        s.split([',', ';', ' ', '\t'])
+            .filter(|item| !item.is_empty())
+            .map(T::from_field)
+            .collect()
+    }
+}
+
+/// Keeps the parser types in [`record!`](crate::record) apart from the closure
+#[doc(hidden)]
+pub fn parser<'a, O, F: FnMut(&'a str) -> VResult<'a, O>>(f: F) -> F {
+    f
+}
+
+/// A nom parser for a line described by a [`Template`](crate::parsers::record::Template). Either
+/// fill a struct with fields of the same names:
+///
+/// ```ignore
+/// record!("{name}: {x:int},{y:int}" => Point { name, x, y })
+/// ```
+///
+/// or bind the fields to typed arguments:
+///
+/// ```ignore
+/// record!("Card {id:int}: {winning:list} | {have:list}", |id: u32, winning: Vec<u32>, have: Vec<u32>| {
+///     Card { id, winning: winning.into_iter().collect(), have }
+/// })
+/// ```
+#[macro_export]
+macro_rules! record {
+    ($template:expr => $name:ident { $($field:ident),* $(,)? }) => {{
+        let template = $crate::parsers::record::Template::with_fields($template, &[$(stringify!($field)),*]);
+        $crate::parsers::record::parser(move |i| {
+            let (rest, record) = template.parse(i)?;
+            Ok((rest, $name { $($field: record.get(stringify!($field))?),* }))
+        })
+    }};
+    ($template:expr, |$($field:ident: $ty:ty),* $(,)?| $body:expr) => {{
+        let template = $crate::parsers::record::Template::with_fields($template, &[$(stringify!($field)),*]);
+        $crate::parsers::record::parser(move |i| {
+            let (rest, record) = template.parse(i)?;
+            $(let $field: $ty = record.get(stringify!($field))?;)*
+            Ok((rest, $body))
+        })
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::parse_all;
+    use nom::{character::complete::line_ending, multi::separated_list1};
+
+    #[derive(Debug, PartialEq)]
+    struct Card {
+        id: u32,
+        winning: Vec<u32>,
+        have: Vec<u8>,
+    }
+
+    #[test]
+    fn fields() {
+        let t = Template::new("Card {id:int}: {winning:list} | {have}");
+        assert_eq!(t.fields().collect::<Vec<_>>(), ["id", "winning", "have"]);
+        let (rest, r) = t.parse("Card   3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1\nCard 4").unwrap();
+        assert_eq!(rest, "\nCard 4");
+        assert_eq!(r.text("winning"), Some("1 21 53 59 44"));
+        assert_eq!(r.get::<Vec<u8>>("have"), Ok(vec![69, 82, 63, 72, 16, 21, 14, 1]));
+
+        let (_, r) = Template::new("{{{name:word}}} = {a}, {b}").parse("{px} = a<2006, q:rfg").unwrap();
+        assert_eq!((r.text("name"), r.text("a"), r.text("b")), (Some("px"), Some("a<2006"), Some("q:rfg")));
+
+        let (rest, r) = Template::new("{key:word}:{value:token}").parse("hcl:#fffffd byr:1937").unwrap();
+        assert_eq!((r.text("key"), r.text("value"), rest), (Some("hcl"), Some("#fffffd"), " byr:1937"));
+    }
+
+    #[test]
+    fn macro_forms() {
+        let card = record!("Card {id:int}: {winning:list} | {have:list}" => Card { id, winning, have });
+        let cards = parse_all("Card 1: 41 48 | 83 86  6\nCard 2: 13 | 61\n", separated_list1(line_ending, card)).unwrap();
+        assert_eq!(cards[1], Card { id: 2, winning: vec![13], have: vec![61] });
+
+        let mut game = record!("Game {id:int}: {draws}", |id: u16, draws: String| (id, draws.len()));
+        assert_eq!(game("Game 12: 3 blue, 4 red"), Ok(("", (12, 13))));
+    }
+
+    #[test]
+    fn errors() {
+        let card = record!("Card {id:int}: {winning:list} | {have:list}" => Card { id, winning, have });
+        let e = parse_all("Card 1: 41 48 | 83\nCard 2: 41 x48 | 83\n", separated_list1(line_ending, card)).unwrap_err();
+        assert_eq!(e.position, Some((2, 9)));
+        assert_eq!(e.to_string(), "parse error at line 2, column 9: invalid value\n2 | Card 2: 41 x48 | 83\n  |         ^\nwhile parsing winning");
+
+        let card = record!("Card {id:int}: {winning:list} | {have:list}" => Card { id, winning, have });
+        let e = parse_all("Card 1: 1 | 300", card).unwrap_err();
+        assert_eq!((e.position, e.context), (Some((1, 13)), vec!["have"]));
+
+        let t = Template::new("Card {id:int}: {winning:list} | {have:list}");
+        assert!(t.parse("Card 1: 2 3").is_err());
+        assert!(t.parse("Card x: 2 | 3").is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "has fields")]
+    fn missing_field() {
+        Template::with_fields("{a} {b:int}", &["a"]);
+    }
+}
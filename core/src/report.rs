@@ -0,0 +1,325 @@
+use std::fmt;
+use std::io::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use serde::{Serialize, Serializer};
+
+use crate::alloc::AllocStats;
+use crate::answers::{Answer, Check};
+use crate::bench::Phase;
+
+/// Output formats of the runner. All machine readable formats share the fields of [`DayResult`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Format> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(anyhow!("Unknown output format {}, use table, json or csv", s)),
+        }
+    }
+}
+
+/// Timings and answers of a single day. Phases that did not run because of an earlier error are
+/// left empty. Allocations are only counted when built with the `alloc-stats` feature.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct DayResult {
+    pub day: u32,
+    #[serde(rename = "parse_ns", serialize_with = "nanos")]
+    pub parse_time: Option<Duration>,
+    #[serde(rename = "part1_ns", serialize_with = "nanos")]
+    pub part1_time: Option<Duration>,
+    #[serde(rename = "part2_ns", serialize_with = "nanos")]
+    pub part2_time: Option<Duration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_alloc: Option<AllocStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1_alloc: Option<AllocStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2_alloc: Option<AllocStats>,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    /// Outcome of comparing the answers with the known answers, only set with `--check`
+    pub check1: Option<Check>,
+    pub check2: Option<Check>,
+    /// Measured while a worker of a day that timed out was still running, the timings are skewed
+    pub contended: bool,
+    pub error: Option<Failure>,
+}
+
+impl DayResult {
+    pub fn total_time(&self) -> Duration {
+        [self.parse_time, self.part1_time, self.part2_time].iter().flatten().sum()
+    }
+
+    pub fn has_alloc(&self) -> bool {
+        self.parse_alloc.is_some() || self.part1_alloc.is_some() || self.part2_alloc.is_some()
+    }
+
+    pub fn alloc(&self, phase: Phase) -> Option<AllocStats> {
+        match phase {
+            Phase::Parse => self.parse_alloc,
+            Phase::Part1 => self.part1_alloc,
+            Phase::Part2 => self.part2_alloc,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FailureKind {
+    Error,
+    Panic,
+    Timeout,
+}
+
+impl FailureKind {
+    /// Name in the machine readable output
+    pub fn name(self) -> &'static str {
+        match self {
+            FailureKind::Error => "error",
+            FailureKind::Panic => "panic",
+            FailureKind::Timeout => "timeout",
+        }
+    }
+}
+
+impl fmt::Display for FailureKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FailureKind::Error => write!(f, "ERR"),
+            FailureKind::Panic => write!(f, "PANIC"),
+            FailureKind::Timeout => write!(f, "TIMEOUT"),
+        }
+    }
+}
+
+/// The reason the phase of a day did not produce a result
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Failure {
+    pub phase: Phase,
+    pub kind: FailureKind,
+    pub message: String,
+}
+
+impl Failure {
+    pub fn new<S: Into<String>>(phase: Phase, kind: FailureKind, message: S) -> Failure {
+        Failure { phase, kind, message: message.into() }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} in {}: {}", self.kind, self.phase, self.message)
+    }
+}
+
+/// The fields of a [`Failure`] as CSV columns, the same fields as in JSON
+const FAILURE_HEADER: &str = "error_phase,error_kind,error_message";
+
+fn failure_fields(failure: Option<&Failure>) -> [Option<String>; 3] {
+    [
+        failure.map(|e| e.phase.to_string()),
+        failure.map(|e| e.kind.name().to_string()),
+        failure.map(|e| e.message.clone()),
+    ]
+}
+
+fn nanos<S: Serializer>(d: &Option<Duration>, s: S) -> Result<S::Ok, S::Error> {
+    match d {
+        Some(d) => s.serialize_u64(d.as_nanos() as u64),
+        None => s.serialize_none(),
+    }
+}
+
+/// Writes [`DayResult`]s or [`BenchRow`]s as a list
+pub fn write_json<W: Write, T: Serialize>(mut w: W, results: &[T]) -> Result<()> {
+    serde_json::to_writer_pretty(&mut w, results)?;
+    writeln!(w)?;
+    Ok(())
+}
+
+/// Writes a line per day. The allocation columns are only added when allocations were counted.
+pub fn write_csv<W: Write>(mut w: W, results: &[DayResult]) -> Result<()> {
+    let with_alloc = results.iter().any(DayResult::has_alloc);
+    write!(w, "day,parse_ns,part1_ns,part2_ns,part1,part2,check1,check2,contended,{}", FAILURE_HEADER)?;
+    if with_alloc {
+        for phase in Phase::ALL {
+            write!(w, ",{0}_allocs,{0}_bytes,{0}_peak", phase)?;
+        }
+    }
+    writeln!(w)?;
+
+    for r in results {
+        let mut fields = vec![
+            Some(r.day.to_string()),
+            r.parse_time.map(|d| d.as_nanos().to_string()),
+            r.part1_time.map(|d| d.as_nanos().to_string()),
+            r.part2_time.map(|d| d.as_nanos().to_string()),
+            r.part1.as_ref().map(Answer::to_line),
+            r.part2.as_ref().map(Answer::to_line),
+            r.check1.map(|c| c.name().to_string()),
+            r.check2.map(|c| c.name().to_string()),
+            Some(r.contended.to_string()),
+        ];
+        fields.extend(failure_fields(r.error.as_ref()));
+        if with_alloc {
+            for a in Phase::ALL.map(|p| r.alloc(p)) {
+                fields.push(a.map(|a| a.count.to_string()));
+                fields.push(a.map(|a| a.bytes.to_string()));
+                fields.push(a.map(|a| a.peak.to_string()));
+            }
+        }
+        let line = fields.iter()
+            .map(|f| csv_field(f.as_deref().unwrap_or("")))
+            .collect::<Vec<_>>()
+            .join(",");
+        writeln!(w, "{}", line)?;
+    }
+    Ok(())
+}
+
+/// A phase of a day in a benchmark report, either timed or failed. Phases that were not run
+/// have no row.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchRow {
+    pub day: u32,
+    pub phase: Phase,
+    #[serde(rename = "min_ns", serialize_with = "nanos")]
+    pub min: Option<Duration>,
+    #[serde(rename = "median_ns", serialize_with = "nanos")]
+    pub median: Option<Duration>,
+    #[serde(rename = "mean_ns", serialize_with = "nanos")]
+    pub mean: Option<Duration>,
+    #[serde(rename = "stddev_ns", serialize_with = "nanos")]
+    pub stddev: Option<Duration>,
+    /// Change of the median against the baseline, in percent
+    pub change: Option<f64>,
+    /// Some runs were measured while a worker that timed out was still running
+    pub contended: bool,
+    pub error: Option<Failure>,
+}
+
+/// Writes a line per day and phase
+pub fn write_bench_csv<W: Write>(mut w: W, rows: &[BenchRow]) -> Result<()> {
+    writeln!(w, "day,phase,min_ns,median_ns,mean_ns,stddev_ns,change,contended,{}", FAILURE_HEADER)?;
+    for r in rows {
+        let mut fields = vec![
+            Some(r.day.to_string()),
+            Some(r.phase.to_string()),
+            r.min.map(|d| d.as_nanos().to_string()),
+            r.median.map(|d| d.as_nanos().to_string()),
+            r.mean.map(|d| d.as_nanos().to_string()),
+            r.stddev.map(|d| d.as_nanos().to_string()),
+            r.change.map(|c| format!("{:.1}", c)),
+            Some(r.contended.to_string()),
+        ];
+        fields.extend(failure_fields(r.error.as_ref()));
+        let line = fields.iter()
+            .map(|f| csv_field(f.as_deref().unwrap_or("")))
+            .collect::<Vec<_>>()
+            .join(",");
+        writeln!(w, "{}", line)?;
+    }
+    Ok(())
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result() -> DayResult {
+        DayResult {
+            day: 3,
+            parse_time: Some(Duration::from_micros(2)),
+            part1_time: Some(Duration::from_nanos(50)),
+            part1: Some("1,2".into()),
+            check1: Some(Check::Fail),
+            error: Some(Failure::new(Phase::Part2, FailureKind::Error, "Part 2 not yet implemented")),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn csv() {
+        let mut out = Vec::new();
+        write_csv(&mut out, &[result()]).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(),
+            "day,parse_ns,part1_ns,part2_ns,part1,part2,check1,check2,contended,error_phase,error_kind,error_message\n\
+             3,2000,50,,\"1,2\",,fail,,false,part2,error,Part 2 not yet implemented\n");
+        assert_eq!(csv_field("a\"b"), "\"a\"\"b\"");
+
+        let mut r = result();
+        r.parse_alloc = Some(AllocStats { count: 2, bytes: 64, peak: 48 });
+        let mut out = Vec::new();
+        write_csv(&mut out, &[r]).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let mut lines = out.lines();
+        assert!(lines.next().unwrap().ends_with(",error_message,parse_allocs,parse_bytes,parse_peak,part1_allocs,part1_bytes,part1_peak,part2_allocs,part2_bytes,part2_peak"));
+        assert!(lines.next().unwrap().ends_with(",2,64,48,,,,,,"));
+    }
+
+    #[test]
+    fn bench_csv() {
+        let timed = BenchRow {
+            day: 2,
+            phase: Phase::Part1,
+            min: Some(Duration::from_nanos(10)),
+            median: Some(Duration::from_nanos(12)),
+            mean: Some(Duration::from_nanos(13)),
+            stddev: Some(Duration::from_nanos(1)),
+            change: Some(-4.25),
+            contended: false,
+            error: None,
+        };
+        let failed = BenchRow {
+            phase: Phase::Part2,
+            min: None, median: None, mean: None, stddev: None, change: None, contended: true,
+            error: Some(Failure::new(Phase::Part2, FailureKind::Timeout, "No result after 1s")),
+            ..timed.clone()
+        };
+        let mut out = Vec::new();
+        write_bench_csv(&mut out, &[timed, failed]).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(),
+            "day,phase,min_ns,median_ns,mean_ns,stddev_ns,change,contended,error_phase,error_kind,error_message\n\
+             2,part1,10,12,13,1,-4.2,false,,,\n2,part2,,,,,,true,part2,timeout,No result after 1s\n");
+    }
+
+    #[test]
+    fn json() {
+        let mut out = Vec::new();
+        write_json(&mut out, &[result()]).unwrap();
+        let v: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(v[0]["day"], 3);
+        assert_eq!(v[0]["parse_ns"], 2000);
+        assert_eq!(v[0]["part2_ns"], serde_json::Value::Null);
+        assert_eq!(v[0]["part1"], "1,2");
+        assert!(v[0].get("parse_alloc").is_none());
+        assert_eq!(v[0]["check1"], "fail");
+        assert_eq!(v[0]["check2"], serde_json::Value::Null);
+        assert_eq!(v[0]["contended"], false);
+        assert_eq!(v[0]["error"]["phase"], "part2");
+        assert_eq!(v[0]["error"]["kind"], "error");
+        assert_eq!(v[0]["error"]["message"], "Part 2 not yet implemented");
+    }
+}
@@ -0,0 +1,642 @@
+use std::path::{Path, PathBuf};
+use std::fs::read_to_string;
+use std::io::{stdin, stdout, Read};
+use std::str::FromStr;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+use std::sync::Mutex;
+use std::thread::{self, JoinHandle};
+use std::time::{Instant, Duration};
+
+use anyhow::{anyhow, Result};
+use rayon::prelude::*;
+use tabular::{Table, row};
+
+use crate::alloc::{self, AllocStats};
+use crate::answers::{Answer, Answers, Check};
+use crate::bench::{bench_day, Baseline, DayBench, Phase};
+use crate::history;
+use crate::report::{self, BenchRow, DayResult, Failure, FailureKind, Format};
+use crate::{SolutionFn, Year};
+
+#[derive(Debug, Default, Clone)]
+pub struct RunOptions {
+    /// Compare the answers with the known good answers stored next to the input
+    pub check: bool,
+    /// Benchmark with this many timed runs instead of running once
+    pub bench: Option<usize>,
+    /// Untimed runs before benchmarking
+    pub warmup: usize,
+    /// Report the change in median time against this baseline file
+    pub baseline: Option<PathBuf>,
+    /// Store the median times of the benchmark as a new baseline
+    pub save_baseline: Option<PathBuf>,
+    /// Output format of the results
+    pub format: Format,
+    /// Run the days in parallel on the rayon thread pool
+    pub parallel: bool,
+    /// Give up on a phase of a day when it runs longer than this
+    pub timeout: Option<Duration>,
+    /// Only run this part, the input is always parsed
+    pub part: Option<u8>,
+    /// Append the timings of runs on the default inputs to this history file
+    pub history: Option<PathBuf>,
+}
+
+impl RunOptions {
+    fn phases(&self) -> Vec<Phase> {
+        match self.part {
+            Some(1) => vec![Phase::Parse, Phase::Part1],
+            Some(2) => vec![Phase::Parse, Phase::Part2],
+            _ => Phase::ALL.to_vec(),
+        }
+    }
+}
+
+/// A selection of days like `1-5,12`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayList(pub Vec<u32>);
+
+impl FromStr for DayList {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<DayList> {
+        let mut days = Vec::new();
+        for range in s.split(',') {
+            let (from, to): (u32, u32) = match range.split_once('-') {
+                Some((from, to)) => (from.trim().parse()?, to.trim().parse()?),
+                None => {
+                    let day = range.trim().parse()?;
+                    (day, day)
+                }
+            };
+            if from == 0 || to > 25 || from > to {
+                return Err(anyhow!("Invalid day range {}", range));
+            }
+            days.extend(from..=to);
+        }
+        days.sort_unstable();
+        days.dedup();
+        Ok(DayList(days))
+    }
+}
+
+pub fn run_day<I: AsRef<Path>>(year: &Year, day: u32, input: I, opts: &RunOptions) -> Result<()> {
+    if let Some(iterations) = opts.bench {
+        let result = bench_input(year, day, input.as_ref(), iterations, opts)?;
+        return report_bench(&[result], opts);
+    }
+
+    let result = run_input(year, day, input.as_ref(), opts)?;
+    if input.as_ref() == year.input_path(day) {
+        record_history(std::slice::from_ref(&result), opts)?;
+    }
+
+    match opts.format {
+        Format::Table => {
+            if let Some(t) = result.parse_time {
+                println!("Day {} parsed ({:?})", day, t);
+            }
+            if let (Some(res1), Some(t)) = (&result.part1, result.part1_time) {
+                println!("Day {} part 1: {} ({:?}){}", day, res1, t, check_suffix(result.check1));
+            }
+            if let (Some(res2), Some(t)) = (&result.part2, result.part2_time) {
+                println!("Day {} part 2: {} ({:?}){}", day, res2, t, check_suffix(result.check2));
+            }
+        },
+        Format::Json => report::write_json(stdout(), std::slice::from_ref(&result))?,
+        Format::Csv => report::write_csv(stdout(), std::slice::from_ref(&result))?,
+    }
+
+    if let Some(e) = result.error {
+        return Err(anyhow!("{}", e));
+    }
+
+    run_status(std::slice::from_ref(&result))
+}
+
+fn record_history(results: &[DayResult], opts: &RunOptions) -> Result<()> {
+    match &opts.history {
+        Some(path) => history::record(path, results, opts.parallel),
+        None => Ok(()),
+    }
+}
+
+fn check_suffix(check: Option<Check>) -> String {
+    check.map(|c| format!(" [{}]", c)).unwrap_or_default()
+}
+
+fn count_failed(results: &[DayResult]) -> usize {
+    results.iter()
+        .flat_map(|r| [r.check1, r.check2])
+        .filter(|&c| c == Some(Check::Fail))
+        .count()
+}
+
+/// An error when an answer did not match or a day did not finish, so scripts can tell
+fn run_status(results: &[DayResult]) -> Result<()> {
+    let failed = count_failed(results);
+    let unfinished = results.iter().filter(|r| r.error.is_some()).count();
+    match (failed, unfinished) {
+        (0, 0) => Ok(()),
+        (f, 0) => Err(anyhow!("{} answer(s) did not match", f)),
+        (0, u) => Err(anyhow!("{} day(s) did not finish", u)),
+        (f, u) => Err(anyhow!("{} answer(s) did not match and {} day(s) did not finish", f, u)),
+    }
+}
+
+/// Solves a day for the input file, capturing the failure of the phase that did not finish
+fn run_input(year: &Year, day: u32, input: &Path, opts: &RunOptions) -> Result<DayResult> {
+    let answers = if opts.check {
+        // input from stdin is checked against the answers of the default input
+        let path = if is_stdin(input) { year.input_path(day) } else { input.to_path_buf() };
+        Some(Answers::for_input(path)?)
+    } else {
+        None
+    };
+
+    let mut result = match read_input(input) {
+        Ok(b) => solve(year.get_solution, day, b, &opts.phases(), opts.timeout),
+        Err(e) => DayResult {
+            day,
+            error: Some(Failure::new(Phase::Parse, FailureKind::Error, format!("Cannot read {}: {}", input.display(), e))),
+            ..Default::default()
+        },
+    };
+
+    if let Some(answers) = answers {
+        // a part that ran without an answer fails the check when its answer is known
+        let phases = opts.phases();
+        let missing = |phase, known: &Option<String>| (phases.contains(&phase) && known.is_some()).then_some(Check::Fail);
+        result.check1 = result.part1.as_ref().map(|r| answers.check_part1(r)).or_else(|| missing(Phase::Part1, &answers.part1));
+        result.check2 = result.part2.as_ref().map(|r| answers.check_part2(r)).or_else(|| missing(Phase::Part2, &answers.part2));
+    }
+
+    Ok(result)
+}
+
+fn is_stdin(input: &Path) -> bool {
+    input == Path::new("-")
+}
+
+/// Reads the puzzle input, `-` reads it from stdin
+fn read_input(input: &Path) -> Result<String> {
+    if is_stdin(input) {
+        let mut s = String::new();
+        stdin().read_to_string(&mut s)?;
+        Ok(s)
+    } else {
+        Ok(read_to_string(input)?)
+    }
+}
+
+/// Resources used by a phase
+#[derive(Debug, Clone, Copy)]
+struct Measured {
+    time: Duration,
+    alloc: Option<AllocStats>,
+}
+
+/// Outcome of a single phase as reported by the worker. Parsing has no answer.
+type PhaseOutcome = std::result::Result<(Option<Answer>, Measured), Failure>;
+
+/// Workers that timed out. They cannot be stopped and keep a core busy until they finish.
+static ABANDONED: Mutex<Vec<JoinHandle<()>>> = Mutex::new(Vec::new());
+
+/// Whether a worker that timed out is still running, the finished ones are forgotten
+fn abandoned_running() -> bool {
+    let mut workers = ABANDONED.lock().unwrap_or_else(|e| e.into_inner());
+    workers.retain(|w| !w.is_finished());
+    !workers.is_empty()
+}
+
+/// Runs the phases of a day in a worker thread and waits at most `timeout` for each of them. A
+/// worker that times out cannot be stopped, it is left running in the background and its
+/// remaining phases are skipped. Results measured while such a worker runs are marked as
+/// contended.
+fn solve(get_solution: SolutionFn, day: u32, input: String, phases: &[Phase], timeout: Option<Duration>) -> DayResult {
+    let mut result = DayResult { day, contended: abandoned_running(), ..Default::default() };
+
+    let (tx, rx) = channel();
+    let spawned = thread::Builder::new()
+        .name(format!("day {}", day))
+        .spawn({
+            let phases = phases.to_vec();
+            move || solve_worker(get_solution, day, &input, &phases, tx)
+        });
+    let worker = match spawned {
+        Ok(w) => w,
+        Err(e) => {
+            result.error = Some(Failure::new(Phase::Parse, FailureKind::Error, e.to_string()));
+            return result;
+        }
+    };
+
+    for &phase in phases {
+        let lost = || Failure::new(phase, FailureKind::Error, "Worker stopped without a result");
+        let received = match timeout {
+            Some(t) => rx.recv_timeout(t).map_err(|e| match e {
+                RecvTimeoutError::Timeout => Failure::new(phase, FailureKind::Timeout, format!("No result after {:?}", t)),
+                RecvTimeoutError::Disconnected => lost(),
+            }),
+            None => rx.recv().map_err(|_| lost()),
+        };
+
+        match received.and_then(|outcome| outcome) {
+            Ok((answer, m)) => match phase {
+                Phase::Parse => {
+                    result.parse_time = Some(m.time);
+                    result.parse_alloc = m.alloc;
+                },
+                Phase::Part1 => {
+                    result.part1 = answer;
+                    result.part1_time = Some(m.time);
+                    result.part1_alloc = m.alloc;
+                },
+                Phase::Part2 => {
+                    result.part2 = answer;
+                    result.part2_time = Some(m.time);
+                    result.part2_alloc = m.alloc;
+                },
+            },
+            Err(f) => {
+                result.error = Some(f);
+                break;
+            }
+        }
+    }
+
+    // a worker of another day may have timed out while this one ran in parallel
+    result.contended |= abandoned_running();
+    if result.error.as_ref().is_some_and(|e| e.kind == FailureKind::Timeout) {
+        ABANDONED.lock().unwrap_or_else(|e| e.into_inner()).push(worker);
+    }
+    result
+}
+
+/// Sends the outcome of every phase in order, stopping at the first failure or when the
+/// supervisor is no longer listening
+fn solve_worker(get_solution: SolutionFn, day: u32, input: &str, phases: &[Phase], tx: Sender<PhaseOutcome>) {
+    let mut p = match guarded(Phase::Parse, || get_solution(day)) {
+        Ok((p, _)) => p,
+        Err(f) => {
+            let _ = tx.send(Err(f));
+            return;
+        }
+    };
+
+    for &phase in phases {
+        let outcome = match phase {
+            Phase::Parse => guarded(phase, || p.parse(input)).map(|((), m)| (None, m)),
+            Phase::Part1 => guarded(phase, || p.part1()).map(|(a, m)| (Some(a), m)),
+            Phase::Part2 => guarded(phase, || p.part2()).map(|(a, m)| (Some(a), m)),
+        };
+        let ok = outcome.is_ok();
+        if tx.send(outcome).is_err() || !ok {
+            return;
+        }
+    }
+}
+
+/// Times `f` and counts its allocations, turning both errors and panics into a [`Failure`] of
+/// `phase`
+fn guarded<T, F: FnOnce() -> Result<T>>(phase: Phase, f: F) -> std::result::Result<(T, Measured), Failure> {
+    let t = Instant::now();
+    let (r, alloc) = alloc::measure(|| catch_unwind(AssertUnwindSafe(f)));
+    let time = t.elapsed();
+    match r {
+        Ok(Ok(v)) => Ok((v, Measured { time, alloc })),
+        Ok(Err(e)) => Err(Failure::new(phase, FailureKind::Error, format!("{:#}", e))),
+        Err(payload) => {
+            let message = payload.downcast_ref::<&str>().map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Unknown panic".to_string());
+            Err(Failure::new(phase, FailureKind::Panic, message))
+        }
+    }
+}
+
+/// Runs all days that have a solution and an input file
+pub fn run_all(year: &Year, opts: &RunOptions) -> Result<()> {
+    let days: Vec<u32> = (1..=25)
+        .filter(|&day| year.input_path(day).exists() && (year.get_solution)(day).is_ok())
+        .collect();
+    run_days(year, &days, opts)
+}
+
+/// Runs the selected days using their default input file
+pub fn run_days(year: &Year, days: &[u32], opts: &RunOptions) -> Result<()> {
+    if let Some(iterations) = opts.bench {
+        return bench_days(year, days, iterations, opts);
+    }
+    if opts.parallel && alloc::ENABLED {
+        return Err(anyhow!("Allocation counts are global and cannot be combined with --parallel"));
+    }
+
+    let run_time = Instant::now();
+    let results = if opts.parallel {
+        days.par_iter()
+            .map(|&day| run_input(year, day, &year.input_path(day), opts))
+            .collect::<Result<Vec<_>>>()?
+    } else {
+        days.iter()
+            .map(|&day| run_input(year, day, &year.input_path(day), opts))
+            .collect::<Result<Vec<_>>>()?
+    };
+    let run_time = run_time.elapsed();
+    record_history(&results, opts)?;
+
+    match opts.format {
+        Format::Table => print_table(&results, run_time, opts),
+        Format::Json => report::write_json(stdout(), &results)?,
+        Format::Csv => report::write_csv(stdout(), &results)?,
+    }
+
+    run_status(&results)
+}
+
+fn print_table(results: &[DayResult], run_time: Duration, opts: &RunOptions) {
+    let mut header = if opts.check {
+        row!("day", "parse", "part1", "part2", "total", "output", "check", "", "check")
+    } else {
+        row!("day", "parse", "part1", "part2", "total", "output", "")
+    };
+    // allocation columns follow the outputs, as count/bytes/peak
+    let output_columns = header.len();
+    let with_alloc = results.iter().any(DayResult::has_alloc);
+    if with_alloc {
+        for phase in Phase::ALL {
+            header.add_cell(format!("{} allocs", phase));
+        }
+    }
+    let mut table = Table::new(&vec!["{:<}"; header.len()].join(" "));
+    table.add_row(header);
+
+    for result in results {
+        // the failed phase shows the kind of failure instead of a time, the message goes in the
+        // output column of the part that did not finish
+        let failed = |phase: Phase| result.error.as_ref().filter(|e| e.phase == phase);
+        let time = |phase: Phase, t: Option<Duration>| match (failed(phase), t) {
+            (Some(e), _) => e.kind.to_string(),
+            (None, Some(t)) => format!("{:?}", t),
+            (None, None) => String::new(),
+        };
+        let output = |phases: &[Phase], answer: &Option<Answer>| match answer {
+            Some(a) => a.to_line(),
+            None => phases.iter()
+                .find_map(|&p| failed(p))
+                .map(|e| format!("{}: {}", e.kind, e.message.lines().next().unwrap_or_default()))
+                .unwrap_or_default(),
+        };
+
+        let mut row = row!(day_cell(result.day, result.contended), time(Phase::Parse, result.parse_time), time(Phase::Part1, result.part1_time), time(Phase::Part2, result.part2_time), format!("{:?}", result.total_time()));
+        row.add_cell(output(&[Phase::Parse, Phase::Part1], &result.part1));
+        if opts.check {
+            row.add_cell(result.check1.map(|c| c.to_string()).unwrap_or_default());
+        }
+        row.add_cell(output(&[Phase::Part2], &result.part2));
+        if opts.check {
+            row.add_cell(result.check2.map(|c| c.to_string()).unwrap_or_default());
+        }
+        while row.len() < output_columns {
+            row.add_cell("");
+        }
+        if with_alloc {
+            for phase in Phase::ALL {
+                row.add_cell(result.alloc(phase).map(format_alloc).unwrap_or_default());
+            }
+        }
+        table.add_row(row);
+    }
+
+    let parse_time: Duration = results.iter().filter_map(|r| r.parse_time).sum();
+    let solution_time: Duration = results.iter().flat_map(|r| [r.part1_time, r.part2_time]).flatten().sum();
+    let cpu_time: Duration = results.iter().map(DayResult::total_time).sum();
+    let footer = [
+        row!("all", format!("{:?}", parse_time),"",format!("{:?}", solution_time), format!("{:?}", cpu_time)),
+        row!("wall", "", "", "", format!("{:?}", run_time)),
+    ];
+    for mut row in footer {
+        while row.len() < table.column_count() {
+            row.add_cell("");
+        }
+        table.add_row(row);
+    }
+    print!("{}", table);
+    print_contended_note(results.iter().any(|r| r.contended));
+
+    // the table only has the first line of a message, parse errors show their location below it
+    for result in results {
+        if let Some(e) = result.error.as_ref().filter(|e| e.message.contains('\n')) {
+            println!("\nday {} {}", result.day, e);
+        }
+    }
+}
+
+/// Contended days are marked with a `*`, explained below the table
+fn day_cell(day: u32, contended: bool) -> String {
+    format!("{}{}", day, if contended { "*" } else { "" })
+}
+
+fn print_contended_note(contended: bool) {
+    if contended {
+        println!("* measured while a day that timed out was still running, the times are skewed");
+    }
+}
+
+fn format_alloc(a: AllocStats) -> String {
+    format!("{}/{}/{}", a.count, format_bytes(a.bytes), format_bytes(a.peak))
+}
+
+fn format_bytes(b: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = b as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{}B", b)
+    } else {
+        format!("{:.1}{}", size, UNITS[unit])
+    }
+}
+
+fn bench_days(year: &Year, days: &[u32], iterations: usize, opts: &RunOptions) -> Result<()> {
+    let results = days.iter()
+        .map(|&day| bench_input(year, day, &year.input_path(day), iterations, opts))
+        .collect::<Result<Vec<_>>>()?;
+    report_bench(&results, opts)
+}
+
+/// Benchmarks a day for the input file. Every run goes through [`solve`], so a failing day is
+/// reported instead of stopping the benchmark.
+fn bench_input(year: &Year, day: u32, input: &Path, iterations: usize, opts: &RunOptions) -> Result<DayBench> {
+    if iterations == 0 {
+        return Err(anyhow!("Need at least one benchmark iteration"));
+    }
+
+    match read_input(input) {
+        Ok(b) => Ok(bench_day(day, iterations, opts.warmup, || solve(year.get_solution, day, b.clone(), &opts.phases(), opts.timeout))),
+        Err(e) => Ok(DayBench {
+            day,
+            error: Some(Failure::new(Phase::Parse, FailureKind::Error, format!("Cannot read {}: {}", input.display(), e))),
+            ..Default::default()
+        }),
+    }
+}
+
+/// A row per phase that was timed or failed, the phases that were not selected are left out
+fn bench_rows(results: &[DayBench], baseline: Option<&Baseline>) -> Vec<BenchRow> {
+    results.iter()
+        .flat_map(|result| Phase::ALL.into_iter().filter_map(move |phase| {
+            let error = result.error.clone().filter(|e| e.phase == phase);
+            let stats = result.phase(phase);
+            if stats.is_none() && error.is_none() {
+                return None;
+            }
+            Some(BenchRow {
+                day: result.day,
+                phase,
+                min: stats.map(|s| s.min),
+                median: stats.map(|s| s.median),
+                mean: stats.map(|s| s.mean),
+                stddev: stats.map(|s| s.stddev),
+                change: stats.zip(baseline).and_then(|(s, b)| b.change(result.day, phase, s.median)),
+                contended: result.contended,
+                error,
+            })
+        }))
+        .collect()
+}
+
+fn report_bench(results: &[DayBench], opts: &RunOptions) -> Result<()> {
+    let baseline = opts.baseline.as_ref().map(Baseline::from_file).transpose()?;
+    let rows = bench_rows(results, baseline.as_ref());
+
+    match opts.format {
+        Format::Table => print_bench_table(&rows, baseline.is_some()),
+        Format::Json => report::write_json(stdout(), &rows)?,
+        Format::Csv => report::write_bench_csv(stdout(), &rows)?,
+    }
+
+    if let Some(path) = &opts.save_baseline {
+        Baseline::from_results(results).save(path)?;
+    }
+
+    let unfinished = results.iter().filter(|r| r.error.is_some()).count();
+    if unfinished > 0 {
+        return Err(anyhow!("{} day(s) did not finish", unfinished));
+    }
+
+    Ok(())
+}
+
+fn print_bench_table(rows: &[BenchRow], with_change: bool) {
+    let mut header = row!("day", "phase", "min", "median", "mean", "stddev");
+    if with_change {
+        header.add_cell("change");
+    }
+    let mut table = Table::new(&vec!["{:<}"; header.len()].join(" "));
+    table.add_row(header);
+
+    let time = |d: Option<Duration>| d.map(|d| format!("{:?}", d)).unwrap_or_default();
+    for r in rows {
+        // a failed phase shows the kind of failure instead of its times
+        let mut row = match &r.error {
+            Some(e) => row!(day_cell(r.day, r.contended), r.phase, e.kind, e.message.lines().next().unwrap_or_default()),
+            None => row!(day_cell(r.day, r.contended), r.phase, time(r.min), time(r.median), time(r.mean), time(r.stddev)),
+        };
+        if with_change && r.error.is_none() {
+            row.add_cell(r.change.map(|c| format!("{:+.1}%", c)).unwrap_or_default());
+        }
+        while row.len() < table.column_count() {
+            row.add_cell("");
+        }
+        table.add_row(row);
+    }
+    print!("{}", table);
+    print_contended_note(rows.iter().any(|r| r.contended));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guarded_failures() {
+        let (v, _) = guarded(Phase::Part1, || Ok(3)).unwrap();
+        assert_eq!(v, 3);
+
+        let e = guarded::<(), _>(Phase::Part1, || Err(anyhow!("broken"))).unwrap_err();
+        assert_eq!(e, Failure::new(Phase::Part1, FailureKind::Error, "broken"));
+
+        let e = guarded::<(), _>(Phase::Part2, || panic!("boom {}", 1)).unwrap_err();
+        assert_eq!(e, Failure::new(Phase::Part2, FailureKind::Panic, "boom 1"));
+    }
+
+    struct Unimplemented;
+    impl crate::Problem for Unimplemented {}
+
+    fn unimplemented(_: u32) -> Result<Box<dyn crate::Problem>> {
+        Ok(Box::new(Unimplemented))
+    }
+
+    #[test]
+    fn solve_reports_failed_phase() {
+        let result = solve(unimplemented, 1, String::new(), &Phase::ALL, Some(Duration::from_secs(5)));
+        assert_eq!(result.error.map(|e| (e.phase, e.kind)), Some((Phase::Parse, FailureKind::Error)));
+        assert!(result.parse_time.is_none());
+    }
+
+    struct Slow;
+    impl crate::Problem for Slow {
+        fn parse(&mut self, _: &str) -> Result<()> {
+            thread::sleep(Duration::from_millis(300));
+            Ok(())
+        }
+    }
+
+    fn slow(_: u32) -> Result<Box<dyn crate::Problem>> {
+        Ok(Box::new(Slow))
+    }
+
+    #[test]
+    fn timeout_contends_later_days() {
+        let result = solve(slow, 1, String::new(), &Phase::ALL, Some(Duration::from_millis(20)));
+        assert_eq!(result.error.map(|e| e.kind), Some(FailureKind::Timeout));
+        assert!(solve(unimplemented, 2, String::new(), &Phase::ALL, None).contended);
+
+        thread::sleep(Duration::from_millis(500));
+        assert!(!abandoned_running());
+    }
+
+    #[test]
+    fn status() {
+        let ok = DayResult { day: 1, check1: Some(Check::Pass), ..Default::default() };
+        let wrong = DayResult { day: 2, check1: Some(Check::Fail), check2: Some(Check::Unknown), ..Default::default() };
+        let crashed = DayResult { day: 3, error: Some(Failure::new(Phase::Part2, FailureKind::Panic, "boom")), ..Default::default() };
+        assert!(run_status(std::slice::from_ref(&ok)).is_ok());
+        assert_eq!(run_status(&[ok.clone(), wrong.clone()]).unwrap_err().to_string(), "1 answer(s) did not match");
+        assert_eq!(run_status(&[ok, crashed.clone()]).unwrap_err().to_string(), "1 day(s) did not finish");
+        assert!(run_status(&[wrong, crashed]).is_err());
+    }
+
+    #[test]
+    fn bytes() {
+        assert_eq!(format_bytes(100), "100B");
+        assert_eq!(format_bytes(1536), "1.5KiB");
+        assert_eq!(format_bytes(3 << 30), "3.0GiB");
+    }
+
+    #[test]
+    fn day_list() {
+        assert_eq!("1-5,12".parse::<DayList>().unwrap(), DayList(vec![1, 2, 3, 4, 5, 12]));
+        assert_eq!("7, 3,3-4".parse::<DayList>().unwrap(), DayList(vec![3, 4, 7]));
+        assert!("0-3".parse::<DayList>().is_err());
+        assert!("5-2".parse::<DayList>().is_err());
+        assert!("24-26".parse::<DayList>().is_err());
+        assert!("x".parse::<DayList>().is_err());
+    }
+}
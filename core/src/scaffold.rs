@@ -0,0 +1,134 @@
+use std::fs::{read_to_string, write, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+
+const TEST_TEMPLATE: &str = r###"
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"
+"#;
+
+    #[test]
+    #[ignore = "example not filled in"]
+    fn parts() {
+        let mut p = Solution::default();
+        p.parse(EXAMPLE).unwrap();
+        assert_eq!(p.part1().unwrap().to_string(), "");
+        assert_eq!(p.part2().unwrap().to_string(), "");
+    }
+}
+"###;
+
+/// Creates `day_NN.rs` in `days_dir` from the `day_00.rs` template in that directory and registers
+/// it in its `mod.rs`. An existing day is never overwritten.
+pub fn new_day<P: AsRef<Path>>(days_dir: P, day: u32) -> Result<PathBuf> {
+    if !(1..=25).contains(&day) {
+        return Err(anyhow!("Day {} is not an advent day", day));
+    }
+
+    let template_path = days_dir.as_ref().join("day_00.rs");
+    let template = read_to_string(&template_path).with_context(|| format!("reading {}", template_path.display()))?;
+    let mod_path = days_dir.as_ref().join("mod.rs");
+    let registry = register(&read_to_string(&mod_path).with_context(|| format!("reading {}", mod_path.display()))?, day)?;
+
+    let path = days_dir.as_ref().join(format!("day_{:02}.rs", day));
+    let mut f = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+        .with_context(|| format!("refusing to overwrite {}", path.display()))?;
+    writeln!(f, "{}", template.trim_end())?;
+    f.write_all(TEST_TEMPLATE.as_bytes())?;
+
+    write(&mod_path, registry)?;
+    Ok(path)
+}
+
+/// Adds the `mod` declaration and the `get_solution` arm for `day` to the source of `days/mod.rs`,
+/// keeping both in day order. The new arm is written like the existing ones.
+pub fn register(src: &str, day: u32) -> Result<String> {
+    let module = format!("day_{:02}", day);
+    let decl = format!("mod {};", module);
+    if src.lines().any(|l| l.trim() == decl) {
+        return Err(anyhow!("{} is already registered", module));
+    }
+
+    let mut lines: Vec<String> = src.lines().map(String::from).collect();
+
+    let decls: Vec<usize> = (0..lines.len()).filter(|&i| line_day(&lines[i], "mod day_").is_some()).collect();
+    let at = match decls.iter().find(|&&i| line_day(&lines[i], "mod day_").is_some_and(|d| d > day)) {
+        Some(&i) => i,
+        None => decls.last().ok_or_else(|| anyhow!("No day modules found"))? + 1,
+    };
+    lines.insert(at, decl);
+
+    let fallback = lines.iter()
+        .position(|l| l.trim_start().starts_with("_ =>"))
+        .ok_or_else(|| anyhow!("No fallback arm in get_solution"))?;
+    let at = (0..fallback)
+        .find(|&i| arm_day(&lines[i]).is_some_and(|d| d > day))
+        .unwrap_or(fallback);
+    let (example_day, example) = (0..fallback)
+        .find_map(|i| arm_day(&lines[i]).map(|d| (d, &lines[i])))
+        .ok_or_else(|| anyhow!("No day arms in get_solution"))?;
+    let arm = example
+        .replacen(&format!("{} =>", example_day), &format!("{} =>", day), 1)
+        .replace(&format!("day_{:02}", example_day), &module);
+    lines.insert(at, arm);
+
+    let mut out = lines.join("\n");
+    out.push('\n');
+    Ok(out)
+}
+
+fn line_day(line: &str, prefix: &str) -> Option<u32> {
+    line.trim().strip_prefix(prefix)?.strip_suffix(';')?.parse().ok()
+}
+
+fn arm_day(line: &str) -> Option<u32> {
+    line.trim().split_once(" =>")?.0.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD: &str = "mod day_01;
+mod day_03;
+
+pub fn get_solution(d: u32) -> Result<Box<dyn Problem>> {
+    match d {
+        1 => Ok(Box::<day_01::Solution>::default()),
+        3 => Ok(Box::<day_03::Solution>::default()),
+        _ => Err(anyhow!(\"Day not available\"))
+    }
+}
+";
+
+    #[test]
+    fn registers_in_order() {
+        let src = register(MOD, 2).unwrap();
+        assert!(src.starts_with("mod day_01;\nmod day_02;\nmod day_03;\n"));
+        assert!(src.contains("1 => Ok(Box::<day_01::Solution>::default()),\n        2 => Ok(Box::<day_02::Solution>::default()),\n        3 =>"));
+
+        let src = register(MOD, 12).unwrap();
+        assert!(src.contains("mod day_03;\nmod day_12;\n\n"));
+        assert!(src.contains("        12 => Ok(Box::<day_12::Solution>::default()),\n        _ =>"));
+    }
+
+    #[test]
+    fn copies_arm_style() {
+        let src = MOD.replace("Box::<day_01::Solution>::default()", "Box::new(day_01::Solution::default())");
+        let src = register(&src, 2).unwrap();
+        assert!(src.contains("        2 => Ok(Box::new(day_02::Solution::default())),\n"));
+    }
+
+    #[test]
+    fn refuses_existing() {
+        assert!(register(MOD, 3).is_err());
+    }
+}
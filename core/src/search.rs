@@ -0,0 +1,299 @@
+//! Shortest paths over any state type. The graph is given as a closure returning the successors
+//! of a state, so it never has to be built up front. States are kept in a `HashMap`, they only
+//! need to be `Clone + Eq + Hash`.
+//!
+//! ```ignore
+//! let steps = grid_steps(&maze, |&c| c != b'#');
+//! let (path, length) = bfs(start, steps, |&c| c == end).ok_or_else(|| anyhow!("No way out"))?;
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::grid::{Coord, Grid};
+
+/// Cost of a path, the `Default` is a cost of zero
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C> Cost for C where C: Copy + Ord + Add<Output = C> + Default {}
+
+/// The states reached by a search, with the cost of the cheapest way there and the state it
+/// came from
+#[derive(Debug, Clone)]
+pub struct Reached<S, C> {
+    states: HashMap<S, (C, Option<S>)>,
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> Reached<S, C> {
+    fn new(start: S) -> Reached<S, C> {
+        Reached { states: HashMap::from([(start, (C::default(), None))]) }
+    }
+
+    pub fn cost(&self, s: &S) -> Option<C> {
+        self.states.get(s).map(|&(c, _)| c)
+    }
+
+    pub fn contains(&self, s: &S) -> bool {
+        self.states.contains_key(s)
+    }
+
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// All reached states with their cost, in no particular order
+    pub fn costs(&self) -> impl Iterator<Item = (&S, C)> {
+        self.states.iter().map(|(s, &(c, _))| (s, c))
+    }
+
+    /// The states from the start up to and including `s`
+    pub fn path_to(&self, s: &S) -> Option<Vec<S>> {
+        let mut path = vec![s.clone()];
+        let mut previous = &self.states.get(s)?.1;
+        while let Some(p) = previous {
+            path.push(p.clone());
+            previous = &self.states[p].1;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    fn path_with_cost(&self, s: Option<S>) -> Option<(Vec<S>, C)> {
+        let s = s?;
+        Some((self.path_to(&s)?, self.cost(&s)?))
+    }
+
+    /// Records `cost` for `s` when it is cheaper than what was known
+    fn improve(&mut self, s: S, cost: C, from: &S) -> bool {
+        match self.states.entry(s) {
+            Entry::Occupied(e) if e.get().0 <= cost => false,
+            Entry::Occupied(mut e) => {
+                e.insert((cost, Some(from.clone())));
+                true
+            },
+            Entry::Vacant(e) => {
+                e.insert((cost, Some(from.clone())));
+                true
+            },
+        }
+    }
+}
+
+fn breadth_first<S, FN, IN, FG>(start: S, mut successors: FN, mut goal: FG) -> (Reached<S, usize>, Option<S>)
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+    FG: FnMut(&S) -> bool,
+{
+    let mut reached = Reached::new(start.clone());
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((s, steps)) = queue.pop_front() {
+        if goal(&s) {
+            return (reached, Some(s));
+        }
+        for next in successors(&s) {
+            if !reached.contains(&next) {
+                reached.states.insert(next.clone(), (steps + 1, Some(s.clone())));
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    (reached, None)
+}
+
+/// An entry of the priority queue, the lowest priority comes out first
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Dijkstra when the heuristic is always zero, A* otherwise
+fn best_first<S, C, FN, IN, FH, FG>(start: S, mut successors: FN, mut heuristic: FH, mut goal: FG) -> (Reached<S, C>, Option<S>)
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FH: FnMut(&S) -> C,
+    FG: FnMut(&S) -> bool,
+{
+    let mut reached = Reached::new(start.clone());
+    let mut queue = BinaryHeap::from([Queued { priority: heuristic(&start), cost: C::default(), state: start }]);
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        // a cheaper way was found after this one was queued
+        if reached.cost(&state).is_some_and(|c| c < cost) {
+            continue;
+        }
+        if goal(&state) {
+            return (reached, Some(state));
+        }
+        for (next, step) in successors(&state) {
+            let cost = cost + step;
+            if reached.improve(next.clone(), cost, &state) {
+                queue.push(Queued { priority: cost + heuristic(&next), cost, state: next });
+            }
+        }
+    }
+    (reached, None)
+}
+
+/// The path with the fewest steps from `start` to a state matching `goal`, and its number of
+/// steps. The path includes `start`.
+pub fn bfs<S, FN, IN, FG>(start: S, successors: FN, goal: FG) -> Option<(Vec<S>, usize)>
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+    FG: FnMut(&S) -> bool,
+{
+    let (reached, found) = breadth_first(start, successors, goal);
+    reached.path_with_cost(found)
+}
+
+/// Every state reachable from `start` with its number of steps
+pub fn bfs_all<S, FN, IN>(start: S, successors: FN) -> Reached<S, usize>
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+{
+    breadth_first(start, successors, |_| false).0
+}
+
+/// The cheapest path from `start` to a state matching `goal`, and its cost. Successors come with
+/// the cost of the step, which must not be negative.
+pub fn dijkstra<S, C, FN, IN, FG>(start: S, successors: FN, goal: FG) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FG: FnMut(&S) -> bool,
+{
+    let (reached, found) = best_first(start, successors, |_| C::default(), goal);
+    reached.path_with_cost(found)
+}
+
+/// Every state reachable from `start` with the cost of the cheapest way there
+pub fn dijkstra_all<S, C, FN, IN>(start: S, successors: FN) -> Reached<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+{
+    best_first(start, successors, |_| C::default(), |_| false).0
+}
+
+/// [`dijkstra`] guided by `heuristic`, an estimate of the cost left to a goal. The path is only
+/// the cheapest when the estimate never exceeds the real cost, like the manhattan distance on a
+/// grid where every step costs at least 1.
+pub fn astar<S, C, FN, IN, FH, FG>(start: S, successors: FN, heuristic: FH, goal: FG) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FH: FnMut(&S) -> C,
+    FG: FnMut(&S) -> bool,
+{
+    let (reached, found) = best_first(start, successors, heuristic, goal);
+    reached.path_with_cost(found)
+}
+
+/// Successors on a grid: the orthogonal neighbours of a cell that are `passable`
+pub fn grid_steps<'a, T, F>(grid: &'a Grid<T>, passable: F) -> impl FnMut(&Coord) -> Vec<Coord> + 'a
+where
+    F: Fn(&T) -> bool + 'a,
+{
+    move |&c| grid.neighbours4(c).filter(|n| passable(n.value)).map(|n| n.coord).collect()
+}
+
+/// Weighted successors on a grid: `cost` gives the cost of entering a cell, or `None` when it
+/// cannot be entered
+pub fn grid_costs<'a, T, C, F>(grid: &'a Grid<T>, cost: F) -> impl FnMut(&Coord) -> Vec<(Coord, C)> + 'a
+where
+    F: Fn(&T) -> Option<C> + 'a,
+{
+    move |&c| grid.neighbours4(c).filter_map(|n| Some((n.coord, cost(n.value)?))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &[u8] = b"\
+        S.#.....\
+        .##.###.\
+        ....#...\
+        .####.#E";
+
+    fn maze() -> (Grid<u8>, Coord, Coord) {
+        let grid = Grid::new(MAZE.iter().copied(), 8, 4);
+        let find = |b| grid.idx_to_coord(MAZE.iter().position(|&c| c == b).unwrap());
+        let (start, end) = (find(b'S'), find(b'E'));
+        (grid, start, end)
+    }
+
+    #[test]
+    fn breadth_first() {
+        let (grid, start, end) = maze();
+        let (path, steps) = bfs(start, grid_steps(&grid, |&c| c != b'#'), |&c| c == end).unwrap();
+        assert_eq!(steps, 14);
+        assert_eq!(path.len(), 15);
+        assert_eq!((path[0], path[14]), (start, end));
+        assert!(path.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+
+        let all = bfs_all(start, grid_steps(&grid, |&c| c != b'#'));
+        assert_eq!(all.len(), MAZE.iter().filter(|&&c| c != b'#').count());
+        assert_eq!(all.cost(&(0, 3).into()), Some(3));
+        assert_eq!(all.path_to(&(0, 2).into()), Some(vec![(0, 0).into(), (0, 1).into(), (0, 2).into()]));
+        assert!(bfs(start, grid_steps(&grid, |&c| c == b'.' || c == b'S'), |&c| c == end).is_none());
+    }
+
+    #[test]
+    fn weighted() {
+        let grid = Grid::new(b"19111\n11191\n".iter().copied().filter(|&c| c != b'\n').map(|c| c - b'0'), 5, 2);
+        let costs = || grid_costs(&grid, |&c| Some(c as u32));
+        let end = Coord::from((4, 1));
+        let (path, cost) = dijkstra((0, 0).into(), costs(), |&c| c == end).unwrap();
+        assert_eq!(cost, 7);
+        assert_eq!(path[1], (0, 1).into());
+
+        assert_eq!(astar((0, 0).into(), costs(), |c: &Coord| c.manhattan(end) as u32, |&c| c == end).map(|(_, c)| c), Some(7));
+        assert_eq!(dijkstra_all((0, 0).into(), costs()).cost(&(2, 0).into()), Some(4));
+
+        // the state can be anything, here a number that is doubled or incremented at a cost
+        let (path, cost) = dijkstra(1u32, |&n| [(n * 2, 1), (n + 1, 3)], |&n| n == 10).unwrap();
+        assert_eq!((path, cost), (vec![1, 2, 4, 5, 10], 6));
+    }
+}
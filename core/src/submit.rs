@@ -0,0 +1,284 @@
+use std::fmt;
+use std::fs::{read_to_string, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::examples::strip_tags;
+use crate::inputs::Fetcher;
+use crate::Answer;
+
+/// How the site responded to a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// An answer was submitted too recently, with the time left to wait when it was given
+    Wait { seconds: Option<u64> },
+    /// The part was already solved, or is not unlocked yet
+    WrongLevel,
+    /// The response was not understood, holds the text of the response
+    Unknown(String),
+}
+
+impl Verdict {
+    fn is_rejection(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait { seconds: Some(s) } => write!(f, "wait {}s", s),
+            Verdict::Wait { seconds: None } => write!(f, "wait"),
+            Verdict::WrongLevel => write!(f, "already solved or locked"),
+            Verdict::Unknown(s) => write!(f, "unknown response: {}", s),
+        }
+    }
+}
+
+/// Reads the verdict from the page returned after posting an answer
+pub fn parse_response(html: &str) -> Verdict {
+    let text = match (html.find("<article>"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+    let text = strip_tags(text);
+
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if text.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if text.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::Wait { seconds: wait_seconds(&text) }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown(text.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+}
+
+/// Parses "you have 1m 20s left to wait"
+fn wait_seconds(text: &str) -> Option<u64> {
+    let end = text.find(" left to wait")?;
+    let start = text[..end].rfind("have ")? + "have ".len();
+    text[start..end].split_whitespace()
+        .map(|t| {
+            let (n, unit) = t.split_at(t.len() - 1);
+            let n: u64 = n.parse().ok()?;
+            match unit {
+                "h" => Some(n * 3600),
+                "m" => Some(n * 60),
+                "s" => Some(n),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+/// A submitted answer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the epoch
+    pub time: u64,
+}
+
+/// All answers submitted for an input. They are stored next to the input file as
+/// `day_NN.ledger`, one JSON attempt per line, and are kept out of git.
+#[derive(Debug)]
+pub struct Ledger {
+    path: PathBuf,
+    pub attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    pub fn path_for<P: AsRef<Path>>(input: P) -> PathBuf {
+        input.as_ref().with_extension("ledger")
+    }
+
+    /// Reads the ledger belonging to `input`, a missing file is an empty ledger
+    pub fn for_input<P: AsRef<Path>>(input: P) -> Result<Ledger> {
+        let path = Ledger::path_for(input);
+        let attempts = if path.exists() {
+            read_to_string(&path)?.lines()
+                .filter(|l| !l.trim().is_empty())
+                .map(serde_json::from_str)
+                .collect::<Result<_, _>>()?
+        } else {
+            Vec::new()
+        };
+        Ok(Ledger { path, attempts })
+    }
+
+    fn record(&mut self, attempt: Attempt) -> Result<()> {
+        let mut f = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(f, "{}", serde_json::to_string(&attempt)?)?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    /// Refuses answers that are known to be wrong or out of range, see [`check_wait`] for the
+    /// time between answers
+    pub fn check(&self, part: u8, answer: &str) -> Result<()> {
+        let attempts = || self.attempts.iter().filter(|a| a.part == part);
+
+        if let Some(a) = attempts().find(|a| a.verdict == Verdict::Correct) {
+            return Err(anyhow!("Part {} is already solved with {}", part, a.answer));
+        }
+        if let Some(a) = attempts().find(|a| a.answer == answer && a.verdict.is_rejection()) {
+            return Err(anyhow!("{} was already rejected as {}", answer, a.verdict));
+        }
+        if let Ok(n) = answer.parse::<i128>() {
+            let bound = |verdict: Verdict| attempts()
+                .filter(move |a| a.verdict == verdict)
+                .filter_map(|a| a.answer.parse::<i128>().ok().map(|b| (b, &a.answer)));
+            if let Some((_, high)) = bound(Verdict::TooHigh).filter(|&(h, _)| n >= h).min() {
+                return Err(anyhow!("{} is too high, {} already was", answer, high));
+            }
+            if let Some((_, low)) = bound(Verdict::TooLow).filter(|&(l, _)| n <= l).max() {
+                return Err(anyhow!("{} is too low, {} already was", answer, low));
+            }
+        }
+        Ok(())
+    }
+
+    /// Submits `answer` to `part` of `day` unless the ledger already rules it out, or the most
+    /// recent attempt in this ledger or in the ledgers of the `other` days asks to wait. Every
+    /// submission is recorded.
+    pub fn submit(&mut self, fetcher: &mut Fetcher, year: i32, day: u32, part: u8, answer: &Answer, other: &[Ledger]) -> Result<Verdict> {
+        if let Answer::Bitmap(_) = answer {
+            return Err(anyhow!("Read the letters from the bitmap and submit them on the site"));
+        }
+        let answer = answer.to_string();
+        if answer.trim().is_empty() {
+            return Err(anyhow!("Refusing to submit an empty answer"));
+        }
+        self.check(part, &answer)?;
+        check_wait(std::iter::once(&*self).chain(other), now())?;
+
+        let verdict = parse_response(&fetcher.post_answer(year, day, part, &answer)?);
+        self.record(Attempt { part, answer, verdict: verdict.clone(), time: now() })?;
+        Ok(verdict)
+    }
+}
+
+/// The site makes an account wait between answers, whatever the day. Refuses to submit while
+/// the most recent attempt in any of `ledgers` was told to wait.
+pub fn check_wait<'a, I: IntoIterator<Item = &'a Ledger>>(ledgers: I, now: u64) -> Result<()> {
+    let latest = ledgers.into_iter().filter_map(|l| l.attempts.last()).max_by_key(|a| a.time);
+    if let Some(Attempt { verdict: Verdict::Wait { seconds: Some(s) }, time, .. }) = latest {
+        if time + s > now {
+            return Err(anyhow!("Wait another {}s before submitting", time + s - now));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inputs::tests::{config, mock_server};
+
+    fn page(text: &str) -> String {
+        format!("<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>", text)
+    }
+
+    #[test]
+    fn responses() {
+        assert_eq!(parse_response(&page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.")), Verdict::Correct);
+        assert_eq!(parse_response(&page("That's not the right answer; your answer is too high.  If you're stuck...")), Verdict::TooHigh);
+        assert_eq!(parse_response(&page("That's not the right answer; your answer is too low.")), Verdict::TooLow);
+        assert_eq!(parse_response(&page("That's not the right answer.  If you're stuck...")), Verdict::Wrong);
+        assert_eq!(parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 20s left to wait.")),
+            Verdict::Wait { seconds: Some(80) });
+        assert_eq!(parse_response(&page("You don't seem to be solving the right level.  Did you already complete it?")), Verdict::WrongLevel);
+        assert_eq!(parse_response("<p>Puzzle inputs\ndiffer</p>"), Verdict::Unknown("Puzzle inputs differ".to_string()));
+    }
+
+    fn attempt(part: u8, answer: &str, verdict: Verdict) -> Attempt {
+        Attempt { part, answer: answer.to_string(), verdict, time: 1000 }
+    }
+
+    #[test]
+    fn ledger_rules() {
+        let mut ledger = Ledger { path: PathBuf::new(), attempts: vec![
+            attempt(1, "100", Verdict::TooHigh),
+            attempt(1, "10", Verdict::TooLow),
+            attempt(1, "abc", Verdict::Wrong),
+        ]};
+        assert!(ledger.check(1, "50").is_ok());
+        assert!(ledger.check(1, "100").is_err());
+        assert!(ledger.check(1, "120").is_err());
+        assert!(ledger.check(1, "10").is_err());
+        assert!(ledger.check(1, "-3").is_err());
+        assert!(ledger.check(1, "abc").is_err());
+        assert!(ledger.check(2, "100").is_ok());
+
+        ledger.attempts.push(attempt(1, "50", Verdict::Correct));
+        assert!(ledger.check(1, "50").is_err());
+    }
+
+    #[test]
+    fn wait_on_any_day() {
+        let day1 = Ledger { path: PathBuf::new(), attempts: vec![attempt(1, "60", Verdict::Wait { seconds: Some(60) })] };
+        let mut day2 = Ledger { path: PathBuf::new(), attempts: vec![Attempt { time: 900, ..attempt(1, "5", Verdict::TooLow) }] };
+        assert!(check_wait([&day2], 1030).is_ok());
+        assert!(check_wait([&day2, &day1], 1030).is_err());
+        assert!(check_wait([&day2, &day1], 1060).is_ok());
+
+        // only the most recent attempt counts
+        day2.attempts.push(Attempt { time: 1010, ..attempt(2, "6", Verdict::TooLow) });
+        assert!(check_wait([&day1, &day2], 1030).is_ok());
+    }
+
+    #[test]
+    fn submit() {
+        let (url, requests) = mock_server(vec![
+            (200, "<article><p>That's not the right answer; your answer is too low.</p></article>"),
+            (200, "<article><p>That's the right answer!</p></article>"),
+        ]);
+        let dir = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("day_07.txt");
+        let mut fetcher = Fetcher::new(config(url));
+
+        let mut ledger = Ledger::for_input(&input).unwrap();
+        assert_eq!(ledger.submit(&mut fetcher, 2023, 7, 2, &Answer::from(41), &[]).unwrap(), Verdict::TooLow);
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2023/day/7/answer "));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=41"));
+
+        let mut ledger = Ledger::for_input(&input).unwrap();
+        assert!(ledger.submit(&mut fetcher, 2023, 7, 2, &Answer::from(40), &[]).is_err());
+        let waiting = Ledger { path: PathBuf::new(), attempts: vec![Attempt { part: 1, answer: "1".to_string(), verdict: Verdict::Wait { seconds: Some(600) }, time: now() }] };
+        assert!(ledger.submit(&mut fetcher, 2023, 7, 2, &Answer::from(42), &[waiting]).is_err());
+        assert_eq!(ledger.submit(&mut fetcher, 2023, 7, 2, &Answer::from(42), &[]).unwrap(), Verdict::Correct);
+
+        let ledger = Ledger::for_input(&input).unwrap();
+        assert_eq!(ledger.attempts.len(), 2);
+        assert_eq!(ledger.attempts[1].answer, "42");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,34 @@
+//! Days of 2015 to 2018 ported from the standalone crates of those years to the [`Problem`]
+//! interface. Only the days listed below are ported, the standalone crates of all days, ported or
+//! not, are kept in the year directories and stay the reference for the answers. The inputs of
+//! 2015, 2016 and 2018 are not in the repository, download them with `--fetch`.
+
+pub use aoc_core::{grid, Answer, Problem};
+
+/// Declares the module of a year with its ported days, and the [`aoc_core::Year`] that runs them
+macro_rules! year {
+    ($module:ident, $year:literal, { $($day:literal => $day_mod:ident),* $(,)? }) => {
+        pub mod $module {
+            $(mod $day_mod;)*
+
+            pub const YEAR: aoc_core::Year = aoc_core::Year {
+                year: $year,
+                get_solution,
+            };
+
+            pub fn get_solution(d: u32) -> anyhow::Result<Box<dyn crate::Problem>> {
+                match d {
+                    $($day => Ok(Box::new($day_mod::Solution::default())),)*
+                    _ => Err(anyhow::anyhow!("Day {} of {} is not ported, run its standalone crate in {}/", d, $year, $year))
+                }
+            }
+        }
+    };
+}
+
+year!(y2015, 2015, { 7 => day_07 });
+year!(y2016, 2016, { 8 => day_08 });
+year!(y2017, 2017, { 1 => day_01, 2 => day_02, 5 => day_05, 6 => day_06, 22 => day_22 });
+year!(y2018, 2018, { 19 => day_19 });
+
+pub const YEARS: [aoc_core::Year; 4] = [y2015::YEAR, y2016::YEAR, y2017::YEAR, y2018::YEAR];
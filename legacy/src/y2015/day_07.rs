@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use regex::Regex;
+
+use crate::{Answer, Problem};
+
+
+#[derive(Clone, Debug)]
+struct Connection {
+    source1: Source,
+    source2: Option<Source>,
+    operator: Option<Operator>
+}
+
+#[derive(Clone, Debug)]
+enum Source {
+    Value(u16),
+    Name(String)
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Debug)]
+enum Operator {
+    AND,
+    NOT,
+    OR,
+    LSHIFT,
+    RSHIFT
+}
+
+#[derive(Clone, Debug)]
+pub struct Circuit {
+    wires: HashMap<String, Connection>,
+    values: HashMap<String, u16>,
+    patterns: [Regex; 3],
+}
+
+impl Circuit {
+    pub fn new() -> Circuit {
+        let hm = HashMap::new();
+        let patterns = [
+            Regex::new(r"^NOT (\w+) -> (\w+)$").unwrap(),
+            Regex::new(r"^(\w+) (AND|OR|LSHIFT|RSHIFT) (\w+) -> (\w+)$").unwrap(),
+            Regex::new(r"^(\w+) -> (\w+)$").unwrap(),
+        ];
+        Circuit {wires: hm, values: HashMap::new(), patterns}
+    }
+
+    pub fn add_connection(&mut self, s: &str) -> Result<()> {
+        let [notre, opre, assre] = &self.patterns;
+        let allnum = |s : &str| s.chars().all(|c| c.is_numeric());
+
+        let (name, connection) = if let Some(caps) = notre.captures(s) {
+            let name = caps.get(2).unwrap().as_str();
+            let s1 = caps.get(1).unwrap().as_str();
+            let source = if allnum(s1) {
+                Source::Value(s1.parse::<u16>().unwrap())
+            } else {
+                Source::Name(s1.to_string())
+            };
+            (name, Connection {source1: source, source2: None, operator: Some(Operator::NOT)})
+        } else if let Some(caps) = opre.captures(s) {
+            let name = caps.get(4).unwrap().as_str();
+            let s1 = caps.get(1).unwrap().as_str();
+            let source1 = if allnum(s1) {
+                Source::Value(s1.parse::<u16>().unwrap())
+            } else {
+                Source::Name(s1.to_string())
+            };
+
+            let s1 = caps.get(3).unwrap().as_str();
+            let source2 = if allnum(s1) {
+                Source::Value(s1.parse::<u16>().unwrap())
+            } else {
+                Source::Name(s1.to_string())
+            };
+
+            let o = caps.get(2).unwrap().as_str();
+            let op = match o {
+                "AND"=> {Some(Operator::AND)},
+                "OR" => {Some(Operator::OR)},
+                "LSHIFT" => {Some(Operator::LSHIFT)},
+                "RSHIFT" => {Some(Operator::RSHIFT)},
+                _ => return Err(anyhow!("Operator unknown"))
+            };
+
+
+            (name, Connection {source1, source2: Some(source2), operator: op})
+
+        } else if let Some(caps) = assre.captures(s) {
+            let name = caps.get(2).unwrap().as_str();
+            let s1 = caps.get(1).unwrap().as_str();
+            let source = if allnum(s1) {
+                Source::Value(s1.parse::<u16>().unwrap())
+            } else {
+                Source::Name(s1.to_string())
+            };
+            (name, Connection {source1: source, source2: None, operator: None})
+            
+        } else {
+            return Err(anyhow!("Cannot parse {}", s));
+        };
+
+        self.wires.insert(name.to_string(), connection);
+        Ok(())
+    }
+
+    pub fn get_wire_value(&mut self, name: String) -> u16 {
+        if let Some(v) = self.values.get(&name) {
+            return *v;
+        }
+        
+        let con = self.wires.get(&name).unwrap().clone();
+        //get the values from the connections:
+        let v1 :u16 = match con.source1.clone() {
+            Source::Value(x) => x,
+            Source::Name(n)  => self.get_wire_value(n)
+        };
+        let v2: Option<u16> = match con.source2.clone() {
+            Some(Source::Value(x)) => Some(x),
+            Some(Source::Name(n))  => Some(self.get_wire_value(n)),
+            None => None
+        };
+        let v = match con.operator {
+            Some(Operator::AND) => v1 & v2.unwrap(),
+            Some(Operator::NOT) => !v1,
+            Some(Operator::OR) => v1 | v2.unwrap(),
+            Some(Operator::LSHIFT) => v1 << v2.unwrap(),
+            Some(Operator::RSHIFT) => v1 >> v2.unwrap(),
+            None => v1
+        };
+        self.values.insert(name, v);
+        v
+    }
+
+    pub fn reset(&mut self) {
+        self.values.clear();
+    }
+}
+
+#[derive(Default)]
+pub struct Solution {
+    circuit: Option<Circuit>,
+}
+
+impl Solution {
+    fn circuit(&self) -> Result<Circuit> {
+        self.circuit.clone().ok_or_else(|| anyhow!("Not parsed"))
+    }
+}
+
+impl Problem for Solution {
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let mut circuit = Circuit::new();
+        for line in i.lines() {
+            circuit.add_connection(line)?;
+        }
+        self.circuit = Some(circuit);
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let mut circuit = self.circuit()?;
+        Ok(circuit.get_wire_value("a".to_string()).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let mut circuit = self.circuit()?;
+        let signal_a = circuit.get_wire_value("a".to_string());
+
+        circuit.reset();
+        //set b to value measured at a
+        let mut newb = signal_a.to_string();
+        newb.push_str(" -> b");
+        circuit.add_connection(newb.as_ref())?;
+        Ok(circuit.get_wire_value("a".to_string()).into())
+    }
+}
+
+#[test]
+fn test() {
+   let mut c = Circuit::new();
+
+    c.add_connection("123 -> x").unwrap();
+    c.add_connection("456 -> y").unwrap();
+    c.add_connection("x AND y -> d").unwrap();
+    c.add_connection("x OR y -> e").unwrap();
+    c.add_connection("x LSHIFT 2 -> f").unwrap();
+    c.add_connection("y RSHIFT 2 -> g").unwrap();
+    c.add_connection("NOT x -> h").unwrap();
+    c.add_connection("NOT y -> i").unwrap();
+
+    assert_eq!(c.get_wire_value("d".to_string()), 72);
+    assert_eq!(c.get_wire_value("e".to_string()), 507);
+    assert_eq!(c.get_wire_value("f".to_string()), 492);
+    assert_eq!(c.get_wire_value("h".to_string()), 65412);
+    assert_eq!(c.get_wire_value("i".to_string()), 65079);
+    assert_eq!(c.get_wire_value("x".to_string()), 123);
+    assert_eq!(c.get_wire_value("y".to_string()), 456);
+}
@@ -0,0 +1,169 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+
+use crate::grid::Grid;
+use crate::{Answer, Problem};
+
+pub struct Screen {
+    pixels: Vec<bool>,
+    width: usize,
+    height: usize
+}
+
+pub enum Command {
+    RotateRow((usize, usize)),
+    RotateCol((usize, usize)),
+    Rect((usize,usize))
+}
+
+impl FromStr for Command {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Command> {
+        let parts: Vec<&str> = s.split(' ').collect();
+        let error = || anyhow!("Error parsing command {}", s);
+        match parts[0] {
+            "rotate" if parts.len() == 5 && parts[2].len() > 2 => {
+                let rc = parts[2][2..].parse::<usize>()?;
+                let amount = parts[4].parse::<usize>()?;
+                match parts[1] {
+                    "row" => Ok(Command::RotateRow((rc, amount))),
+                    "column" => Ok(Command::RotateCol((rc, amount))),
+                    _ => Err(error())
+                }
+            },
+            "rect" if parts.len() == 2 => {
+                let (a, b) = parts[1].split_once('x').ok_or_else(error)?;
+                Ok(Command::Rect((a.parse()?, b.parse()?)))
+            },
+            _ => Err(error())
+        }
+    }
+}
+
+impl fmt::Display for Screen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.pixels.iter().enumerate().map(|p| {
+            let n = p.0 > 0 && (p.0 + 1) % self.width == 0;
+            match *p.1 {
+                true if n => "#\n",
+                false if n => ".\n",
+                true  => "#",
+                false => ".",
+            }
+        }).collect::<String>())
+    }
+}
+
+impl Screen {
+    pub fn new(w: usize, h: usize) -> Screen {
+        Screen { width: w, height: h, pixels: vec![false; w * h] }
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, to: bool) {
+        self.pixels[y*self.width + x] = to;
+    }
+
+    fn rect(&mut self, a: usize, b: usize) -> Result<()> {
+        if a > self.width || b > self.height {
+            return Err(anyhow!("Rectangle {}x{} does not fit the screen", a, b));
+        }
+        for x in 0..a {
+            for y in 0 ..b {
+                self.set_pixel(x,y, true);
+            }
+        }
+        Ok(())
+    }
+
+    fn rotate_row(&mut self, row: usize, amount: usize) -> Result<()> {
+        if row >= self.height {
+            return Err(anyhow!("Row {} is outside the screen", row));
+        }
+        let pos = row * self.width;
+        let amount = self.width - amount % self.width;
+
+        self.pixels[pos..pos+amount].reverse();
+        self.pixels[pos+amount..pos+self.width].reverse();
+        self.pixels[pos..pos+self.width].reverse();
+        Ok(())
+    }
+
+    fn rotate_col(&mut self, col: usize, amount: usize) -> Result<()> {
+        if col >= self.width {
+            return Err(anyhow!("Column {} is outside the screen", col));
+        }
+
+        let amount = amount % self.height;
+        let mut col_coords: Vec<usize> = (0..self.height).map(|r| r*self.width + col).collect();
+        let coord_values: Vec<bool> = col_coords.iter().map(|&c| self.pixels[c]).collect();
+
+        col_coords[..amount].reverse();
+        col_coords[amount..].reverse();
+        col_coords.reverse();
+
+        for (c, v) in col_coords.into_iter().zip(coord_values) {
+            self.pixels[c] = v;
+        }
+        Ok(())
+    }
+
+    pub fn do_command(&mut self, command: &Command) -> Result<()> {
+        match *command {
+            Command::RotateRow((row, n)) => self.rotate_row(row, n),
+            Command::RotateCol((col, n)) => self.rotate_col(col, n),
+            Command::Rect((a,b)) => self.rect(a, b),
+        }
+    }
+
+    pub fn sum_lit(&self) -> usize{
+        self.pixels.iter().filter(|&&p| p).count()
+    }
+}
+
+
+#[derive(Default)]
+pub struct Solution {
+    commands: Vec<Command>,
+}
+
+impl Solution {
+    fn screen(&self) -> Result<Screen> {
+        let mut s = Screen::new(50, 6);
+        for command in &self.commands {
+            s.do_command(command)?;
+        }
+        Ok(s)
+    }
+}
+
+impl Problem for Solution {
+    fn parse(&mut self, i: &str) -> Result<()> {
+        self.commands = i.lines().map(str::parse).collect::<Result<_>>()?;
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.screen()?.sum_lit().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let s = self.screen()?;
+        Ok(Grid::new(s.pixels, s.width, s.height).into())
+    }
+}
+
+#[test]
+fn test() {
+    let mut s = Screen::new(7, 3);
+    for c in ["rect 3x2", "rotate column x=1 by 1", "rotate row y=0 by 4", "rotate column x=1 by 1"] {
+        s.do_command(&c.parse().unwrap()).unwrap();
+    }
+    assert_eq!(s.to_string(), ".#..#.#\n#.#....\n.#.....\n");
+    assert_eq!(s.sum_lit(), 6);
+    for c in ["rect 8x1", "rotate row y=3 by 1", "rotate column x=7 by 1"] {
+        assert!(s.do_command(&c.parse().unwrap()).is_err());
+    }
+}
@@ -0,0 +1,54 @@
+use anyhow::{anyhow, Result};
+
+use crate::{Answer, Problem};
+
+fn captcha(s: &str, skip: usize) -> u32 {
+    s.chars()
+        .zip(s.chars().skip(skip).chain(s.chars()))
+        .filter(|e| e.0 == e.1)
+        .map(|e| e.0.to_digit(10).unwrap())
+        .sum()
+}
+
+#[derive(Default)]
+pub struct Solution {
+    input: String,
+}
+
+impl Problem for Solution {
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let i = i.trim();
+        if !i.chars().all(|c| c.is_ascii_digit()) {
+            return Err(anyhow!("Input should only contain digits"));
+        }
+        self.input = i.to_string();
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(captcha(&self.input, 1).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(captcha(&self.input, self.input.len() / 2).into())
+    }
+}
+
+
+#[test]
+fn test_1() {
+    assert_eq!(captcha("1122", 1), 3);
+    assert_eq!(captcha("1111", 1), 4);
+    assert_eq!(captcha("1234", 1), 0);
+    assert_eq!(captcha("91212129", 1), 9);
+}
+
+#[test]
+fn test_2() {
+    assert_eq!(captcha("1212", 2), 6);
+    assert_eq!(captcha("1221", 2), 0);
+    assert_eq!(captcha("123425", 3), 4);
+    assert_eq!(captcha("123123", 3), 12);
+    assert_eq!(captcha("12131415", 4), 4);
+}
+
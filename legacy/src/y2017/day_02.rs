@@ -0,0 +1,58 @@
+use anyhow::{anyhow, Result};
+
+use crate::{Answer, Problem};
+
+fn range_diff(v: &[i32]) -> Option<i32> {
+    Some(v.iter().max()? - v.iter().min()?)
+}
+
+fn div_pair(v: &[i32]) -> Option<i32> {
+    for (i, x) in v.iter().enumerate() {
+        for y in v.iter().skip(i + 1) {
+            if x % y == 0 {
+                return Some(x / y);
+            } else if y % x == 0 {
+                return Some(y / x);
+            }
+        }
+    }
+    None
+}
+
+#[derive(Default)]
+pub struct Solution {
+    rows: Vec<Vec<i32>>,
+}
+
+impl Problem for Solution {
+    fn parse(&mut self, i: &str) -> Result<()> {
+        self.rows = i.lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| l.split_whitespace()
+                .map(|s| s.parse().map_err(|e| anyhow!("Error parsing number from: {}: {}", s, e)))
+                .collect())
+            .collect::<Result<_>>()?;
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        self.rows.iter()
+            .map(|r| range_diff(r).ok_or_else(|| anyhow!("Empty row")))
+            .sum::<Result<i32>>()
+            .map(Answer::from)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        self.rows.iter()
+            .map(|r| div_pair(r).ok_or_else(|| anyhow!("No divisible pair in row: {:?}", r)))
+            .sum::<Result<i32>>()
+            .map(Answer::from)
+    }
+}
+
+#[test]
+fn checksums() {
+    assert_eq!(range_diff(&[5, 1, 9, 5]), Some(8));
+    assert_eq!(div_pair(&[9, 4, 7, 3]), Some(3));
+    assert_eq!(div_pair(&[3, 8, 6, 5]), Some(2));
+}
@@ -0,0 +1,52 @@
+use anyhow::{anyhow, Result};
+
+use crate::{Answer, Problem};
+
+/// Follows the jumps until they lead out of the list, `adjust` gives the change of an offset
+/// after jumping from it
+fn steps<F: Fn(i32) -> i32>(mut v: Vec<i32>, adjust: F) -> usize {
+    let mut pos = 0;
+    let mut n = 0;
+    loop {
+        n +=1;
+        let o = v[pos as usize];
+        v[pos as usize] += adjust(o);
+        pos += o;
+        if pos < 0 || pos as usize >= v.len() {
+            break;
+        }
+    }
+    n
+}
+
+#[derive(Default)]
+pub struct Solution {
+    offsets: Vec<i32>,
+}
+
+impl Problem for Solution {
+    fn parse(&mut self, i: &str) -> Result<()> {
+        self.offsets = i.lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| l.trim().parse().map_err(|e| anyhow!("Invalid offset {}: {}", l, e)))
+            .collect::<Result<_>>()?;
+        if self.offsets.is_empty() {
+            return Err(anyhow!("No offsets"));
+        }
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(steps(self.offsets.clone(), |_| 1).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(steps(self.offsets.clone(), |o| if o > 2 { -1 } else { 1 }).into())
+    }
+}
+
+#[test]
+fn jumps() {
+    assert_eq!(steps(vec![0, 3, 0, 1, -3], |_| 1), 5);
+    assert_eq!(steps(vec![0, 3, 0, 1, -3], |o| if o > 2 { -1 } else { 1 }), 10);
+}
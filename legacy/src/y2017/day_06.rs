@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+use std::iter::repeat_n;
+
+use anyhow::{anyhow, Result};
+
+use crate::{Answer, Problem};
+
+struct Mem {
+    banks: Vec<usize>,
+}
+
+/// find the max, resolve ties by choosing the lowest index. This behaviour is
+/// different from the stdlib
+/// The max value is replaced with 0
+/// Returns a tuple (index_max, max)
+fn take_first_max(d: &mut [usize]) -> (usize, usize) {
+    let mut max = 0;
+    let mut max_i = 0;
+
+    for (i, v) in d.iter().enumerate() {
+        if max < *v {
+            max = *v;
+            max_i = i
+        }
+    }
+    d[max_i] = 0;
+
+    (max_i, max)
+}
+
+impl Mem {
+    fn new(v: &[usize]) -> Mem {
+        let banks = v.to_vec();
+        Mem { banks }
+    }
+
+    /// Returns the number of cycles until a state repeats and the cycle that state was first
+    /// seen at
+    fn redistribute(&mut self) -> (usize, usize) {
+        let mut seen = HashMap::new();
+        seen.insert(self.banks.to_vec(), 0);
+        let n = self.banks.len();
+        let mut count = 0;
+        loop {
+            let (i, max) = take_first_max(&mut self.banks);
+            let take_more_than_one = if max / n > 0 { n } else { 0 };
+            let toadd = repeat_n(max / n, take_more_than_one).chain(repeat_n(1, max % n));
+            for (i, a) in (i+1..n).chain(0..i+1).cycle().zip(toadd) {
+                self.banks[i] += a;
+            }
+            count +=1;
+            if let Some(&first) = seen.get(&self.banks) {
+                return (count, first)
+            }
+            seen.insert(self.banks.to_vec(), count);
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Solution {
+    banks: Vec<usize>,
+}
+
+impl Problem for Solution {
+    fn parse(&mut self, i: &str) -> Result<()> {
+        self.banks = i.split_whitespace()
+            .map(|s| s.parse().map_err(|e| anyhow!("Invalid bank {}: {}", s, e)))
+            .collect::<Result<_>>()?;
+        if self.banks.is_empty() {
+            return Err(anyhow!("No memory banks"));
+        }
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(Mem::new(&self.banks).redistribute().0.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let (n, first) = Mem::new(&self.banks).redistribute();
+        Ok((n - first).into())
+    }
+}
+
+#[test]
+fn smax() {
+    assert_eq!(take_first_max(&mut [0,1,1,3,4,0]), (4,4));
+    assert_eq!(take_first_max(&mut [5,5,1,3,4,0]), (0,5));
+    assert_eq!(take_first_max(&mut [4,1,1,3,5,5]), (4,5));
+}
+
+#[test]
+fn redis() {
+    let mut m = Mem::new(&[0,2,7,0]);
+    assert_eq!(m.redistribute(), (5, 1));
+}
@@ -0,0 +1,104 @@
+use anyhow::{anyhow, Result};
+
+use crate::{Answer, Problem};
+use crate::grid::{Coord, Grid, SparseGrid};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Infection {
+    #[default]
+    Clean,
+    Weakened,
+    Infected,
+    Flagged,
+}
+
+/// Directions are steps with y pointing down
+fn turn_right(d: Coord) -> Coord {
+    Coord { x: -d.y, y: d.x }
+}
+
+fn turn_left(d: Coord) -> Coord {
+    Coord { x: d.y, y: -d.x }
+}
+
+fn reverse(d: Coord) -> Coord {
+    Coord { x: -d.x, y: -d.y }
+}
+
+#[derive(Debug, Clone)]
+struct Cluster {
+    nodes: SparseGrid<Infection>,
+    start: Coord,
+}
+
+impl Cluster {
+    /// Runs `n` bursts of the virus carrier starting in the middle facing up, returns the number
+    /// of bursts that infected a node. The evolved virus weakens and flags nodes in between.
+    fn bursts(&self, n: usize, evolved: bool) -> usize {
+        let mut nodes = self.nodes.clone();
+        let mut pos = self.start;
+        let mut direction = Coord { x: 0, y: -1 };
+        let mut infected = 0;
+        for _ in 0..n {
+            let node = &mut nodes[pos];
+            let (next, turn): (Infection, fn(Coord) -> Coord) = match (*node, evolved) {
+                (Infection::Clean, false) => (Infection::Infected, turn_left),
+                (Infection::Clean, true) => (Infection::Weakened, turn_left),
+                (Infection::Weakened, _) => (Infection::Infected, |d| d),
+                (Infection::Infected, false) => (Infection::Clean, turn_right),
+                (Infection::Infected, true) => (Infection::Flagged, turn_right),
+                (Infection::Flagged, _) => (Infection::Clean, reverse),
+            };
+            *node = next;
+            infected += (next == Infection::Infected) as usize;
+            direction = turn(direction);
+            pos = pos + direction;
+        }
+        infected
+    }
+}
+
+#[derive(Default)]
+pub struct Solution {
+    cluster: Option<Cluster>,
+}
+
+impl Solution {
+    fn cluster(&self) -> Result<&Cluster> {
+        self.cluster.as_ref().ok_or_else(|| anyhow!("Input not parsed"))
+    }
+}
+
+fn parse(s: &str) -> Result<Cluster> {
+    let grid = Grid::from_str_with(s, |c| match c {
+        '#' => Ok(Infection::Infected),
+        '.' => Ok(Infection::Clean),
+        _ => Err(anyhow!("Expected # or .")),
+    })?;
+    let start = Coord { x: (grid.dim_x / 2) as isize, y: (grid.dim_y / 2) as isize };
+    Ok(Cluster { nodes: SparseGrid::from_grid(&grid, Infection::Clean), start })
+}
+
+impl Problem for Solution {
+    fn parse(&mut self, i: &str) -> Result<()> {
+        self.cluster = Some(parse(i)?);
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.cluster()?.bursts(10_000, false).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.cluster()?.bursts(10_000_000, true).into())
+    }
+}
+
+#[test]
+fn bursts() {
+    let cluster = parse("..#\n#..\n...\n").unwrap();
+    assert_eq!(cluster.start, Coord { x: 1, y: 1 });
+    assert_eq!(cluster.bursts(70, false), 41);
+    assert_eq!(cluster.bursts(10_000, false), 5587);
+    assert_eq!(cluster.bursts(100, true), 26);
+}
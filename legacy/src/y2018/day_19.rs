@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+
+use crate::{Answer, Problem};
+
+type Opcode = fn(&mut [i64; 6], i64, i64, usize);
+
+#[derive(Clone)]
+pub struct Computer {
+    registers: [i64; 6],
+    mnemonics: HashMap<&'static str, usize>,
+    opcodes: Vec<Opcode>,
+    ip_reg: usize,
+    program: Option<Vec<Instruction>>
+}
+
+fn is_register(v: i64) -> bool {
+    (0..6).contains(&v)
+}
+
+fn make_opcode(f: Opcode) -> Opcode {
+    f
+}
+
+impl Computer {
+    pub fn new() -> Computer {
+        let mnemonics: HashMap<_, usize> = vec!["addr", "addi", "mulr", "muli", "banr", "bani",
+        "borr", "bori", "setr", "seti", "gtir", "gtri", "gtrr", "eqir", "eqri", "eqrr"]
+            .into_iter().enumerate().map(|(p, i)| (i, p))
+            .collect();
+
+        let opcodes = vec![
+            make_opcode(|r, a, b, c| r[c] = r[a as usize] + r[b as usize]),
+            make_opcode(|r, a, b, c| r[c] = r[a as usize] + b),
+            make_opcode(|r, a, b, c| r[c] = r[a as usize] * r[b as usize]),
+            make_opcode(|r, a, b, c| r[c] = r[a as usize] * b),
+            make_opcode(|r, a, b, c| r[c] = r[a as usize] & r[b as usize]),
+            make_opcode(|r, a, b, c| r[c] = r[a as usize] & b),
+            make_opcode(|r, a, b, c| r[c] = r[a as usize] | r[b as usize]),
+            make_opcode(|r, a, b, c| r[c] = r[a as usize] | b),
+            make_opcode(|r, a, _, c| r[c] = r[a as usize]),
+            make_opcode(|r, a, _, c| r[c] = a),
+            make_opcode(|r, a, b, c| r[c] = if a > r[b as usize] { 1 } else { 0 }),
+            make_opcode(|r, a, b, c| r[c] = if r[a as usize] > b { 1 } else { 0 }),
+            make_opcode(|r, a, b, c| r[c] = if r[a as usize] > r[b as usize] { 1 } else { 0 }),
+            make_opcode(|r, a, b, c| r[c] = if a == r[b as usize] { 1 } else { 0 }),
+            make_opcode(|r, a, b, c| r[c] = if r[a as usize] == b { 1 } else { 0 }),
+            make_opcode(|r, a, b, c| r[c] = if r[a as usize] == r[b as usize] { 1 } else { 0 }),
+        ];
+
+        Computer { registers: [0; 6], mnemonics, opcodes, ip_reg: 0, program: None }
+    }
+
+    pub fn set_register(&mut self, reg: usize, value: i64) {
+        self.registers[reg] = value;
+    }
+
+    pub fn get_register(&self, reg: usize) -> i64{
+        self.registers[reg]
+    }
+
+    pub fn load_program(&mut self, s: &str) -> Result<()> {
+        let mut lines = s.lines();
+        let ip_reg = lines.next().ok_or_else(|| anyhow!("No ip header")).and_then(|l| {
+            let ip = l.strip_prefix("#ip ").ok_or_else(|| anyhow!("No ip header"))?;
+            ip.trim().parse::<usize>().map_err(|_| anyhow!("Cannot parse ip register"))
+        })?;
+        if ip_reg >= 6 {
+            return Err(anyhow!("Invalid ip register {}", ip_reg));
+        }
+
+        let program = lines.map(|l| {
+            let mut parts = l.split_whitespace();
+            let mut next = || parts.next().ok_or_else(|| anyhow!("Error parsing instruction {}", l));
+            let cmd = next()?;
+            if let Some(&opcode) = self.mnemonics.get(cmd) {
+                let a = next()?.parse()?;
+                let b = next()?.parse()?;
+                let c = next()?.parse()?;
+                // a is a value for seti, gtir and eqir, b is a register when the mnemonic ends
+                // with r, except for setr which ignores b
+                let reads_a = !(cmd == "seti" || cmd.ends_with("ir"));
+                let reads_b = cmd.ends_with('r') && cmd != "setr";
+                if c >= 6 || (reads_a && !is_register(a)) || (reads_b && !is_register(b)) {
+                    return Err(anyhow!("Invalid register in {}", l));
+                }
+                Ok(Instruction { opcode, a, b, c })
+            } else {
+                Err(anyhow!("Uknown instruction {}", cmd))
+            }
+        }).collect::<Result<Vec<Instruction>>>()?;
+
+        self.ip_reg = ip_reg;
+        self.program = Some(program);
+
+        Ok(())
+    }
+
+    /// Runs the program until the instruction pointer leaves the program or reaches `stop_at`,
+    /// returning the number of executed instructions
+    pub fn run(&mut self, stop_at: Option<usize>) -> Result<usize> {
+        if let Some(ref program) = self.program {
+            let mut it = 0;
+            let mut ip = self.registers[self.ip_reg] as usize;
+            while let Some(instruction) = program.get(ip) {
+                if it > 0 && Some(ip) == stop_at {
+                    break;
+                }
+                it += 1;
+                self.registers[self.ip_reg] = ip as i64;
+                self.opcodes[instruction.opcode](&mut self.registers, instruction.a, instruction.b, instruction.c);
+                ip = self.registers[self.ip_reg] as usize + 1;
+            }
+            Ok(it)
+        } else {
+            Err(anyhow!("Load a program before calling run"))
+        }
+    }
+
+}
+
+#[derive(Debug, Clone)]
+struct Instruction {
+    opcode: usize,
+    a: i64,
+    b: i64,
+    c: usize,
+}
+
+#[derive(Default)]
+pub struct Solution {
+    computer: Option<Computer>,
+}
+
+impl Solution {
+    fn computer(&self) -> Result<Computer> {
+        self.computer.clone().ok_or_else(|| anyhow!("Not parsed"))
+    }
+}
+
+fn sum_of_divisors(n: i64) -> i64 {
+    (1..).take_while(|d| d * d <= n)
+        .filter(|d| n % d == 0)
+        .map(|d| if d * d == n { d } else { d + n / d })
+        .sum()
+}
+
+impl Problem for Solution {
+    fn parse(&mut self, i: &str) -> Result<()> {
+        let mut c = Computer::new();
+        c.load_program(i)?;
+        self.computer = Some(c);
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let mut c = self.computer()?;
+        c.run(None)?;
+        Ok(c.get_register(0).into())
+    }
+
+    /// With register 0 set to 1 the program sums the divisors of a much larger number, very
+    /// slowly. The number is set up before the program jumps back to instruction 1.
+    fn part2(&self) -> Result<Answer> {
+        let mut c = self.computer()?;
+        c.set_register(0, 1);
+        c.run(Some(1))?;
+        let n = (0..6).map(|r| c.get_register(r)).max().unwrap();
+        Ok(sum_of_divisors(n).into())
+    }
+}
+
+#[test]
+fn test() {
+    let mut c = Computer::new();
+    c.load_program("#ip 0\nseti 5 0 1\nseti 6 0 2\naddi 0 1 0\naddr 1 2 3\nsetr 1 0 0\nseti 8 0 4\nseti 9 0 5\n").unwrap();
+    c.run(None).unwrap();
+    assert_eq!(c.get_register(0), 6);
+    assert_eq!(sum_of_divisors(10551264), 27941760);
+    assert!(c.load_program("#ip 0\naddr 6 0 1\n").is_err());
+    assert!(c.load_program("#ip 0\nmulr 1 -1 1\n").is_err());
+    assert!(c.load_program("#ip 0\nseti 7 0 1\ngtir 9 1 2\n").is_ok());
+}
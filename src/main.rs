@@ -0,0 +1,273 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::{Parser, Subcommand};
+use chrono::prelude::*;
+use chrono::offset::FixedOffset;
+use anyhow::{anyhow, Result};
+
+use aoc_core::{run_day, run_days, run_all, DayList, RunOptions, Year};
+use aoc_core::report::Format;
+use aoc_core::{examples, history, scaffold};
+use aoc_core::answers::Answers;
+use aoc_core::inputs::{Config, Fetcher};
+use aoc_core::submit::{Ledger, Verdict};
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: aoc_core::alloc::Counting = aoc_core::alloc::Counting;
+
+/// All years with solutions, the last one is the default
+fn years() -> Vec<Year> {
+    let mut years = aoc_legacy::YEARS.to_vec();
+    years.extend([aoc2020::YEAR, aoc2023::YEAR]);
+    years
+}
+
+#[derive(Debug, Parser)]
+#[clap(name = "aoc", about = "Advent of Code runner.")]
+struct Opt {
+    #[clap(subcommand)]
+    command: Option<Command>,
+    /// Year to run, defaults to the most recent year
+    #[clap(short = 'y', long, global = true)]
+    year: Option<i32>,
+    #[clap(short = 'd', long = "day", value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: Option<u32>,
+    /// Input file for a single day, use - to read from stdin
+    #[clap(short = 'i', long = "input", conflicts_with_all = ["all", "days"])]
+    input: Option<PathBuf>,
+    #[clap(long, conflicts_with_all = ["day", "days"])]
+    all: bool,
+    /// Days to run, for example 1-5,12
+    #[clap(long, conflicts_with = "day")]
+    days: Option<DayList>,
+    /// Only run part 1 or 2
+    #[clap(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Allow all days to run without looking at the clock
+    #[clap(long)]
+    year_is_done: bool,
+    /// Do not add the timings of this run to YEAR/history.jsonl
+    #[clap(long)]
+    no_history: bool,
+    /// Download missing inputs, the session token is read from AOC_SESSION or aoc.toml
+    #[clap(long)]
+    fetch: bool,
+    /// Check the answers against YEAR/inputs/day_NN.answers
+    #[clap(long)]
+    check: bool,
+    /// Benchmark using N timed runs, reporting statistics per phase
+    #[clap(long, value_name = "N")]
+    bench: Option<usize>,
+    /// Untimed runs before benchmarking
+    #[clap(long, default_value_t = 1)]
+    warmup: usize,
+    /// Compare the benchmark against a baseline file
+    #[clap(long)]
+    baseline: Option<PathBuf>,
+    /// Save the benchmark as a baseline file
+    #[clap(long)]
+    save_baseline: Option<PathBuf>,
+    /// Output format: table, json or csv
+    #[clap(long, default_value = "table")]
+    format: Format,
+    /// Run all days in parallel
+    #[clap(long)]
+    parallel: bool,
+    /// Give up on a parse or part that runs longer than this many seconds
+    #[clap(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Create YEAR/src/days/day_NN.rs from the day_00 template and register it in days/mod.rs
+    New {
+        day: u32,
+    },
+    /// Extract the examples of a saved puzzle page into YEAR/examples/day_NN_M.txt
+    Examples {
+        day: u32,
+        /// The saved page, defaults to YEAR/puzzles/day_NN.html
+        #[clap(long)]
+        html: Option<PathBuf>,
+    },
+    /// Show the timings recorded in YEAR/history.jsonl, flagging days that got slower than their
+    /// best run
+    History {
+        /// Days to show, for example 1-5,12
+        #[clap(long)]
+        days: Option<DayList>,
+        /// Number of recent runs in the trend
+        #[clap(long, default_value_t = 5)]
+        last: usize,
+        /// Percentage above the best time that counts as a regression
+        #[clap(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Solve a part for YEAR/inputs/day_NN.txt and submit the answer, attempts are recorded in
+    /// YEAR/inputs/day_NN.ledger
+    Submit {
+        day: u32,
+        #[clap(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+}
+
+/// Puzzles unlock at midnight EST
+fn est() -> FixedOffset {
+    FixedOffset::west_opt(5 * 3600).unwrap()
+}
+
+fn is_unlocked(year: i32, day: u32) -> bool {
+    est().with_ymd_and_hms(year, 12, day, 0, 0, 0)
+        .single()
+        .is_some_and(|unlock| Utc::now() >= unlock)
+}
+
+/// The puzzle of today, when it is December of `year`
+fn today(year: i32) -> Option<u32> {
+    let now = Utc::now().with_timezone(&est());
+    (now.year() == year && now.month() == 12 && now.day() <= 25).then(|| now.day())
+}
+
+/// Downloads the inputs of `days` that are not there yet
+fn fetch_missing(year: &Year, days: &[u32]) -> Result<()> {
+    let mut fetcher = None;
+    for &day in days {
+        let path = year.input_path(day);
+        if path.exists() {
+            continue;
+        }
+        let fetcher = match fetcher {
+            Some(ref mut f) => f,
+            None => fetcher.insert(Fetcher::new(Config::load()?)),
+        };
+        fetcher.ensure(year.year, day, &path)?;
+        eprintln!("downloaded {}", path.display());
+    }
+    Ok(())
+}
+
+fn submit(year: &Year, day: u32, part: u8) -> Result<()> {
+    let input = year.input_path(day);
+    let mut p = (year.get_solution)(day)?;
+    p.parse(&std::fs::read_to_string(&input)?)?;
+    let answer = if part == 1 { p.part1()? } else { p.part2()? };
+    println!("Day {} part {}: {}", day, part, answer);
+
+    // the wait between answers applies to the whole account, so the other ledgers are consulted
+    let other = years().iter()
+        .flat_map(|y| (1..=25).map(|d| y.input_path(d)))
+        .filter(|p| *p != input && Ledger::path_for(p).exists())
+        .map(Ledger::for_input)
+        .collect::<Result<Vec<_>>>()?;
+    let mut ledger = Ledger::for_input(&input)?;
+    let verdict = ledger.submit(&mut Fetcher::new(Config::load()?), year.year, day, part, &answer, &other)?;
+    println!("{}", verdict);
+
+    if verdict == Verdict::Correct {
+        let mut answers = Answers::for_input(&input)?;
+        let known = if part == 1 { &mut answers.part1 } else { &mut answers.part2 };
+        *known = Some(answer.to_line());
+        answers.save_for_input(&input)?;
+    }
+    Ok(())
+}
+
+fn parse_seconds(s: &str) -> Result<Duration> {
+    Ok(Duration::try_from_secs_f64(s.parse()?)?)
+}
+
+fn main() -> Result<()> {
+    let opt = Opt::parse();
+
+    let years = years();
+    let year = match opt.year {
+        Some(y) => years.iter().find(|year| year.year == y).ok_or_else(|| anyhow!("No solutions for {}", y))?,
+        None => years.last().unwrap(),
+    };
+
+    match opt.command {
+        Some(Command::New { day }) => {
+            let path = scaffold::new_day(year.days_dir(), day)?;
+            println!("created {}", path.display());
+            return Ok(());
+        },
+        Some(Command::Examples { day, html }) => {
+            let html = html.unwrap_or_else(|| year.puzzle_path(day));
+            for path in examples::save(html, year.examples_dir(), day)? {
+                println!("created {}", path.display());
+            }
+            return Ok(());
+        },
+        Some(Command::Submit { day, part }) => return submit(year, day, part),
+        Some(Command::History { days, last, threshold }) => {
+            let entries = history::load(year.history_path())?;
+            let regressions = history::report(&entries, days.as_ref().map(|d| &d.0[..]), last, threshold);
+            if regressions > 0 {
+                return Err(anyhow!("{} day(s) are more than {}% slower than their best run", regressions, threshold));
+            }
+            return Ok(());
+        },
+        None => {},
+    }
+
+    let run_opts = RunOptions {
+        check: opt.check,
+        bench: opt.bench,
+        warmup: opt.warmup,
+        baseline: opt.baseline,
+        save_baseline: opt.save_baseline,
+        format: opt.format,
+        parallel: opt.parallel,
+        timeout: opt.timeout,
+        part: opt.part,
+        history: (!opt.no_history).then(|| year.history_path()),
+    };
+
+    if opt.all {
+        if opt.fetch {
+            let days: Vec<u32> = (1..=25)
+                .filter(|&d| opt.year_is_done || is_unlocked(year.year, d))
+                .filter(|&d| (year.get_solution)(d).is_ok())
+                .collect();
+            fetch_missing(year, &days)?;
+        }
+        return run_all(year, &run_opts);
+    }
+
+    let days = match (opt.days, opt.day) {
+        (Some(DayList(days)), _) => days,
+        (None, Some(day)) => vec![day],
+        (None, None) => vec![today(year.year).ok_or_else(|| anyhow!("No puzzle today, select a day with --day or --days"))?],
+    };
+    if !opt.year_is_done {
+        if let Some(day) = days.iter().find(|&&d| !is_unlocked(year.year, d)) {
+            return Err(anyhow!("Day {} of {} is not unlocked yet, use --year-is-done to run it anyway", day, year.year));
+        }
+    }
+
+    // days without a solution, like the days of the older years that are not ported, fail
+    // before their input is read or fetched
+    for &day in &days {
+        (year.get_solution)(day)?;
+    }
+
+    if opt.fetch && opt.input.is_none() {
+        fetch_missing(year, &days)?;
+    }
+
+    if let [day] = days[..] {
+        let input = opt.input.unwrap_or_else(|| year.input_path(day));
+
+        if opt.format == Format::Table {
+            println!("run day {} for {}", day, input.display());
+        }
+
+        run_day(year, day, input, &run_opts)
+    } else {
+        run_days(year, &days, &run_opts)
+    }
+}